@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::error::{column_of, Error, Result};
+use crate::runner::Part;
+
+const ANSWERS: &str = "answers file";
+
+/// Outcome of comparing a computed answer with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// carries the recorded answer
+    Fail(String),
+    /// no answer recorded for this day and part
+    Unknown,
+}
+
+/// Recorded puzzle answers, read from a small TOML file of the form
+///
+/// ```toml
+/// [day1]
+/// part1 = 69281
+/// part2 = "201524"
+///
+/// [day10]
+/// part2 = """
+/// ##..
+/// ..##"""
+/// ```
+///
+/// Only the subset of TOML needed for this is supported: `[dayN]` tables,
+/// `part1`/`part2` keys, integers, basic strings, multi-line basic strings
+/// and `#` comments.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, Part), String>,
+}
+
+fn parse_error(lineno: usize, column: usize, msg: impl Into<String>) -> Error {
+    Error::file(ANSWERS, lineno, column, msg)
+}
+
+/// Resolves the escape sequences of a basic string, on error returns the
+/// rest of `raw` starting at the unsupported escape sequence.
+fn unescape(raw: &str) -> std::result::Result<String, &str> {
+    let mut res = String::with_capacity(raw.len());
+    let mut chars = raw.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'n')) => res.push('\n'),
+            Some((_, 't')) => res.push('\t'),
+            Some((_, '"')) => res.push('"'),
+            Some((_, '\\')) => res.push('\\'),
+            _ => return Err(&raw[i..]),
+        }
+    }
+    Ok(res)
+}
+
+/// Byte offset of the first quote in `s` that is not escaped.
+fn closing_quote(s: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Only whitespace or a comment may follow a value, `rest` is the part of
+/// `line` after it.
+fn check_trailing(line: &str, rest: &str, lineno: usize) -> Result<()> {
+    let rest = rest.trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(parse_error(lineno, column_of(line, rest), "unexpected characters after value"));
+    }
+    Ok(())
+}
+
+impl Answers {
+    /// reads an answers file, errors are reported with the path of the file
+    pub fn open(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| match e {
+            Error::File { line, column, msg, .. } => Error::file(path.display().to_string(), line, column, msg),
+            e => e,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        let mut day: Option<u8> = None;
+        let mut lines = text.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+        while let Some((lineno, raw)) = lines.next() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |part: &str, msg: &str| parse_error(lineno, column_of(raw, part), msg);
+
+            if let Some(header) = line.strip_prefix('[') {
+                let (name, rest) = header.split_once(']').ok_or_else(|| err(line, "unterminated table header"))?;
+                check_trailing(raw, rest, lineno)?;
+                let d = name.trim().strip_prefix("day")
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .ok_or_else(|| err(line, "expected table header [dayN]"))?;
+                day = Some(d);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| err(line, "expected key = value"))?;
+            let key = key.trim();
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                k => return Err(err(k, &format!("unknown key '{}'", k))),
+            };
+            let d = day.ok_or_else(|| err(line, "answer outside of a [dayN] table"))?;
+
+            let value = value.trim();
+            let answer = if let Some(first) = value.strip_prefix("\"\"\"") {
+                // multi-line string, escapes are resolved line by line
+                let (mut no, mut phys, mut seg) = (lineno, raw, first);
+                let mut body: Vec<String> = Vec::new();
+                let rest = loop {
+                    let (text, rest) = match seg.find("\"\"\"") {
+                        Some(at) => (&seg[..at], Some(&seg[at + 3..])),
+                        None => (seg, None),
+                    };
+                    body.push(unescape(text).map_err(|at| {
+                        parse_error(no, column_of(phys, at), "unsupported escape sequence")
+                    })?);
+                    if let Some(rest) = rest {
+                        break rest;
+                    }
+                    (no, phys) = lines.next().ok_or_else(|| err(value, "unterminated multi-line string"))?;
+                    seg = phys;
+                };
+                check_trailing(phys, rest, no)?;
+                // a newline right after the opening delimiter is trimmed
+                if body.len() > 1 && body[0].is_empty() {
+                    body.remove(0);
+                }
+                body.join("\n")
+            } else if let Some(quoted) = value.strip_prefix('"') {
+                let end = closing_quote(quoted).ok_or_else(|| err(value, "unterminated string"))?;
+                check_trailing(raw, &quoted[end + 1..], lineno)?;
+                unescape(&quoted[..end]).map_err(|at| err(at, "unsupported escape sequence"))?
+            } else {
+                let (num, _comment) = value.split_once('#').unwrap_or((value, ""));
+                let num = num.trim();
+                // answers are compared as rendered by the solvers, so 069281 or +5 are normalized
+                num.parse::<i64>().map_err(|_| err(num, &format!("invalid integer '{}'", num)))?.to_string()
+            };
+
+            if answers.expected.insert((d, part), answer).is_some() {
+                return Err(err(key, "duplicate answer"));
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn verify(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.expected(day, part) {
+            Some(exp) if exp == answer => Verdict::Pass,
+            Some(exp) => Verdict::Fail(String::from(exp)),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_err(text: &str) -> String {
+        Answers::parse(text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn integers_are_normalized() {
+        let answers = Answers::parse("[day1]\npart1 = 069281\npart2 = +5  # comment\n[day2]\npart1 = -0\n").unwrap();
+        assert_eq!(answers.expected(1, Part::One), Some("69281"));
+        assert_eq!(answers.verify(1, Part::Two, "5"), Verdict::Pass);
+        assert_eq!(answers.verify(2, Part::One, "0"), Verdict::Pass);
+        assert_eq!(answers.verify(2, Part::Two, "0"), Verdict::Unknown);
+        assert_eq!(answers.verify(1, Part::One, "69282"), Verdict::Fail(String::from("69281")));
+    }
+
+    #[test]
+    fn strings_and_escapes() {
+        let answers = Answers::parse("[day5]\npart1 = \"CMZ\" # top crates\npart2 = \"a\\\"b\\\\c\\td\"\n").unwrap();
+        assert_eq!(answers.expected(5, Part::One), Some("CMZ"));
+        assert_eq!(answers.expected(5, Part::Two), Some("a\"b\\c\td"));
+        assert_eq!(parse_err("[day5]\npart1 = \"a\\qb\""), "answers file line 2, column 11: unsupported escape sequence");
+    }
+
+    #[test]
+    fn multi_line_strings() {
+        let answers = Answers::parse("[day10]\npart2 = \"\"\"\n##..\n..##\"\"\"\n[day11]\npart2 = \"\"\"a\\tb\nc\"\"\" # x\n").unwrap();
+        assert_eq!(answers.expected(10, Part::Two), Some("##..\n..##"));
+        assert_eq!(answers.expected(11, Part::Two), Some("a\tb\nc"));
+        assert_eq!(parse_err("[day10]\npart2 = \"\"\"\n##..\n..\\x##\"\"\""), "answers file line 4, column 3: unsupported escape sequence");
+        assert_eq!(parse_err("[day10]\npart2 = \"\"\"\n##..\n"), "answers file line 2, column 9: unterminated multi-line string");
+        assert_eq!(parse_err("[day10]\npart2 = \"\"\"\n##\"\"\" x"), "answers file line 3, column 7: unexpected characters after value");
+    }
+
+    #[test]
+    fn invalid_files() {
+        assert_eq!(parse_err("part1 = 1"), "answers file line 1, column 1: answer outside of a [dayN] table");
+        assert_eq!(parse_err("[day1]\npart1 = 1\n  part1 = 2"), "answers file line 3, column 3: duplicate answer");
+        assert_eq!(parse_err("[day1]\npart1 = 1 2"), "answers file line 2, column 9: invalid integer '1 2'");
+        assert_eq!(parse_err("[day1]\npart1 = \"1\" 2"), "answers file line 2, column 13: unexpected characters after value");
+        assert_eq!(parse_err("[day1] x"), "answers file line 1, column 8: unexpected characters after value");
+        assert_eq!(parse_err("[day1]\npart1 = \"1"), "answers file line 2, column 9: unterminated string");
+        assert_eq!(parse_err("[day1]\npart3 = 1"), "answers file line 2, column 1: unknown key 'part3'");
+        assert_eq!(parse_err("[day1"), "answers file line 1, column 1: unterminated table header");
+        assert_eq!(parse_err("[week1]"), "answers file line 1, column 1: expected table header [dayN]");
+        assert_eq!(parse_err("[day1]\npart1"), "answers file line 2, column 1: expected key = value");
+    }
+
+    #[test]
+    fn recorded_answers_parse() {
+        let answers = Answers::parse(include_str!("../answers.toml")).unwrap();
+        assert_eq!(answers.expected(17, Part::Two), Some("1565517241382"));
+    }
+}
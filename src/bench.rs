@@ -0,0 +1,154 @@
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::puzzle::Puzzle;
+use crate::runner::Part;
+
+/// Timed phase of a solver run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    /// `None` if there are no samples.
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        if n == 0 {
+            return None;
+        }
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        Some(Self { min: sorted[0], median, max: sorted[n - 1] })
+    }
+}
+
+/// Samples of all timed phases of a single day.
+#[derive(Debug)]
+pub struct DayTimings {
+    pub day: u8,
+    pub iterations: usize,
+    pub samples: Vec<(Phase, Vec<Duration>)>,
+}
+
+impl DayTimings {
+    pub fn summaries(&self) -> impl Iterator<Item = (Phase, Summary)> + '_ {
+        self.samples.iter().filter_map(|(phase, s)| Summary::of(s).map(|sm| (*phase, sm)))
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = black_box(f());
+    (res, start.elapsed())
+}
+
+/// Runs parse and the requested parts of puzzle `P` `iterations` times.
+/// Reading the input is not timed, it is done once by the caller.
+pub fn measure<P: Puzzle>(input: &str, parts: &[Part], iterations: usize) -> Result<DayTimings> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let (parsed, d) = time(|| P::parse(input));
+        let parsed = parsed?;
+        parse.push(d);
+        if parts.contains(&Part::One) {
+            part1.push(time(|| P::part1(&parsed)).1);
+        }
+        if parts.contains(&Part::Two) {
+            part2.push(time(|| P::part2(&parsed)).1);
+        }
+    }
+    let samples = vec![(Phase::Parse, parse), (Phase::Part1, part1), (Phase::Part2, part2)];
+    Ok(DayTimings { day: P::DAY, iterations, samples })
+}
+
+/// Report with one `day,phase,iterations,min_ns,median_ns,max_ns` row per phase.
+pub fn to_csv(timings: &[DayTimings]) -> String {
+    let mut out = String::from("day,phase,iterations,min_ns,median_ns,max_ns\n");
+    for t in timings {
+        for (phase, s) in t.summaries() {
+            writeln!(out, "{},{},{},{},{},{}", t.day, phase.name(), t.iterations,
+                     s.min.as_nanos(), s.median.as_nanos(), s.max.as_nanos()).unwrap();
+        }
+    }
+    out
+}
+
+/// Same content as [`to_csv`] as a JSON array of objects.
+pub fn to_json(timings: &[DayTimings]) -> String {
+    let rows: Vec<String> = timings.iter().flat_map(|t| {
+        t.summaries().map(move |(phase, s)| {
+            format!("  {{\"day\": {}, \"phase\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    t.day, phase.name(), t.iterations, s.min.as_nanos(), s.median.as_nanos(), s.max.as_nanos())
+        })
+    }).collect();
+    if rows.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ns(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn median_of_odd_and_even_counts() {
+        let s = Summary::of(&ns(&[30, 10, 20])).unwrap();
+        assert_eq!((s.min, s.median, s.max), (Duration::from_nanos(10), Duration::from_nanos(20), Duration::from_nanos(30)));
+        // the mean of the two middle samples
+        let s = Summary::of(&ns(&[40, 10, 25, 20])).unwrap();
+        assert_eq!(s.median, Duration::from_nanos(22));
+        assert!(Summary::of(&[]).is_none());
+    }
+
+    #[test]
+    fn reports() {
+        // part 2 was not requested, it has no samples and no row
+        let timings = [DayTimings { day: 7, iterations: 2, samples: vec![
+            (Phase::Parse, ns(&[100, 300])),
+            (Phase::Part1, ns(&[5, 7])),
+            (Phase::Part2, Vec::new()),
+        ] }];
+        assert_eq!(to_csv(&timings), "day,phase,iterations,min_ns,median_ns,max_ns\n\
+                                      7,parse,2,100,200,300\n\
+                                      7,part1,2,5,6,7\n");
+        assert_eq!(to_json(&timings), "[\n  \
+            {\"day\": 7, \"phase\": \"parse\", \"iterations\": 2, \"min_ns\": 100, \"median_ns\": 200, \"max_ns\": 300},\n  \
+            {\"day\": 7, \"phase\": \"part1\", \"iterations\": 2, \"min_ns\": 5, \"median_ns\": 6, \"max_ns\": 7}\n]\n");
+        assert_eq!(to_csv(&[]), "day,phase,iterations,min_ns,median_ns,max_ns\n");
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+}
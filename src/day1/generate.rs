@@ -0,0 +1,22 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day1;
+
+impl Generate for Day1 {
+    /// `size` elves (at least 3) carrying 1 to 15 food items each
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for elf in 0..size.max(3) {
+            if elf > 0 {
+                out.push('\n');
+            }
+            for _ in 0..rng.range(1, 15) {
+                writeln!(out, "{}", rng.range(1000, 60000)).unwrap();
+            }
+        }
+        out
+    }
+}
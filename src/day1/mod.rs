@@ -0,0 +1,167 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+mod stats;
+
+pub use stats::Stats;
+
+/// calorie sums of the elves in a line stream, as (elf index, calorie sum)
+///
+/// groups are separated by blank lines, the last group is flushed at the end
+/// of the stream whether or not it is followed by a blank line
+pub struct Groups<I> {
+    lines: I,
+    lineno: usize,
+    elf: usize,
+}
+
+impl<I, S> Groups<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    pub fn new(lines: impl IntoIterator<IntoIter = I>) -> Self {
+        Self { lines: lines.into_iter(), lineno: 0, elf: 0 }
+    }
+}
+
+impl<I, S> Iterator for Groups<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = Result<(usize, u32)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut sum: Option<u32> = None;
+        for line in self.lines.by_ref() {
+            self.lineno += 1;
+            let lstr = line.as_ref();
+            if lstr.is_empty() {
+                sum = Some(sum.unwrap_or(0));
+                break;
+            }
+            match lstr.parse::<u32>() {
+                Ok(cal) => sum = Some(sum.unwrap_or(0) + cal),
+                Err(e) => {
+                    let msg = format!("invalid calorie count '{}': {}", lstr, e);
+                    return Some(Err(Error::parse(Day1::DAY, self.lineno, 1, msg)));
+                }
+            }
+        }
+        let sum = sum?;
+        self.elf += 1;
+        Some(Ok((self.elf - 1, sum)))
+    }
+}
+
+/// the `k` elves carrying the most calories, as (elf index, calorie sum)
+/// sorted by decreasing calorie sum, ties are ranked by elf index
+///
+/// only `k` elves are kept in memory at any time
+pub fn top_k(groups: impl IntoIterator<Item = (usize, u32)>, k: usize) -> Vec<(usize, u32)> {
+    // min-heap on the calorie sum, on ties the later elf is evicted first
+    let mut heap: BinaryHeap<Reverse<(u32, Reverse<usize>)>> = BinaryHeap::with_capacity(k + 1);
+    for (elf, sum) in groups {
+        heap.push(Reverse((sum, Reverse(elf))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|Reverse((sum, Reverse(elf)))| (elf, sum)).collect()
+}
+
+/// ranks the elves of a line stream, see `Groups` and `top_k`
+pub fn rank<S: AsRef<str>>(lines: impl IntoIterator<Item = S>, k: usize) -> Result<Vec<(usize, u32)>> {
+    let mut err = None;
+    let groups = Groups::new(lines).map_while(|group| group.map_err(|e| err = Some(e)).ok());
+    let ranked = top_k(groups, k);
+    match err {
+        Some(e) => Err(e),
+        None => Ok(ranked),
+    }
+}
+
+pub struct Day1;
+
+impl Puzzle for Day1 {
+    const DAY: u8 = 1;
+    /// the top three (elf index, calorie sum) by decreasing calorie sum
+    type Input = Vec<(usize, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        rank(input.lines(), 3)
+    }
+
+    fn part1(ranked: &Self::Input) -> u32 {
+        ranked.first().map_or(0, |&(_, sum)| sum)
+    }
+
+    fn part2(ranked: &Self::Input) -> u32 {
+        ranked.iter().map(|&(_, sum)| sum).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 24000);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), 45000);
+    }
+
+    #[test]
+    fn rank_any_k() {
+        assert_eq!(rank(EXAMPLE.lines(), 2).unwrap(), vec![(3, 24000), (2, 11000)]);
+        assert_eq!(rank(EXAMPLE.lines(), 10).unwrap().len(), 5);
+        assert!(rank(EXAMPLE.lines(), 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn last_group_is_flushed() {
+        assert_eq!(rank(["1", "", "5", "5"], 1).unwrap(), vec![(1, 10)]);
+        assert_eq!(rank(["1", "", "5", "5", ""], 1).unwrap(), vec![(1, 10)]);
+    }
+
+    #[test]
+    fn ties_keep_the_earlier_elf() {
+        assert_eq!(rank(["7", "", "3", "", "7"], 2).unwrap(), vec![(0, 7), (2, 7)]);
+    }
+
+    #[test]
+    fn example_stats() {
+        let stats = Stats::from_lines(EXAMPLE.lines()).unwrap().unwrap();
+        assert_eq!((stats.count(), stats.min(), stats.max(), stats.total()), (5, 4000, 24000, 55000));
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median(), 10000.0);
+        assert_eq!(stats.percentile(25.0), 6000.0);
+        assert_eq!(stats.percentile(90.0), 18800.0);
+        assert!((stats.stddev - 6985.70).abs() < 0.01);
+        let hist = stats.histogram(4);
+        assert_eq!(hist.iter().map(|&(_, _, count)| count).sum::<usize>(), 5);
+        assert_eq!(hist[0], (4000, 9000, 2));
+        assert_eq!(hist[3], (19003, 24003, 1));
+        assert!(stats.histogram(0).is_empty());
+    }
+
+    #[test]
+    fn no_elves_no_stats() {
+        assert!(Stats::from_lines("".lines()).unwrap().is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use crate::error::Result;
+
+use super::Groups;
+
+const PERCENTILES: [f64; 6] = [10.0, 25.0, 50.0, 75.0, 90.0, 99.0];
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 50;
+
+/// summary statistics over the calorie sums of all elves
+#[derive(Debug)]
+pub struct Stats {
+    /// calorie sums in increasing order
+    totals: Vec<u32>,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// `None` if there are no elves
+    pub fn new(totals: impl IntoIterator<Item = u32>) -> Option<Self> {
+        let mut totals: Vec<u32> = totals.into_iter().collect();
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_unstable();
+        let n = totals.len() as f64;
+        let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / n;
+        // population standard deviation, every elf is counted
+        let var = totals.iter().map(|&t| (t as f64 - mean).powi(2)).sum::<f64>() / n;
+        Some(Self { totals, mean, stddev: var.sqrt() })
+    }
+
+    /// statistics of the elves in a line stream, see `Groups`
+    pub fn from_lines<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Result<Option<Self>> {
+        let totals = Groups::new(lines).map(|group| group.map(|(_, sum)| sum)).collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(totals))
+    }
+
+    pub fn count(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.totals.iter().map(|&t| t as u64).sum()
+    }
+
+    pub fn min(&self) -> u32 {
+        self.totals[0]
+    }
+
+    pub fn max(&self) -> u32 {
+        self.totals[self.totals.len() - 1]
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// `p`-th percentile (0 to 100), interpolated linearly between the two
+    /// closest ranks
+    pub fn percentile(&self, p: f64) -> f64 {
+        let rank = p.clamp(0.0, 100.0) / 100.0 * (self.totals.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+        let (xlo, xhi) = (self.totals[lo] as f64, self.totals[hi] as f64);
+        xlo + (xhi - xlo) * (rank - lo as f64)
+    }
+
+    /// number of elves in each of `bins` equally wide ranges from min to
+    /// max, as (first, last, count), empty for no bins
+    pub fn histogram(&self, bins: usize) -> Vec<(u32, u32, usize)> {
+        if bins == 0 {
+            return Vec::new();
+        }
+        let (min, max) = (self.min() as u64, self.max() as u64);
+        let width = (max - min) / bins as u64 + 1;
+        let mut counts = vec![0; bins];
+        for &t in &self.totals {
+            counts[((t as u64 - min) / width) as usize] += 1;
+        }
+        counts.into_iter().enumerate().map(|(i, count)| {
+            let first = min + i as u64 * width;
+            (first as u32, (first + width - 1) as u32, count)
+        }).collect()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<8} {:>12}", "elves", self.count())?;
+        writeln!(f, "{:<8} {:>12}", "total", self.total())?;
+        writeln!(f, "{:<8} {:>12}", "min", self.min())?;
+        writeln!(f, "{:<8} {:>12}", "max", self.max())?;
+        writeln!(f, "{:<8} {:>12.1}", "mean", self.mean)?;
+        writeln!(f, "{:<8} {:>12.1}", "median", self.median())?;
+        writeln!(f, "{:<8} {:>12.1}", "stddev", self.stddev)?;
+        for p in PERCENTILES {
+            writeln!(f, "{:<8} {:>12.1}", format!("p{}", p), self.percentile(p))?;
+        }
+        writeln!(f)?;
+        let hist = self.histogram(HISTOGRAM_BINS);
+        let most = hist.iter().map(|&(_, _, count)| count).max().unwrap_or(0).max(1);
+        for (first, last, count) in hist {
+            let bar = "#".repeat(count * HISTOGRAM_WIDTH / most);
+            writeln!(f, "{:>8}..{:<8} {:>6} {}", first, last, count, bar)?;
+        }
+        Ok(())
+    }
+}
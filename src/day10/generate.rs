@@ -0,0 +1,30 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day10;
+
+impl Generate for Day10 {
+    /// program of at least `size` instructions that runs for at least the 240
+    /// cycles drawn on the CRT, the sprite stays within the screen
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        let mut x: i64 = 1;
+        let mut cycles = 0;
+        let mut ninstr = 0;
+        while cycles < 240 || ninstr < size {
+            if rng.chance(1, 3) {
+                writeln!(out, "noop").unwrap();
+                cycles += 1;
+            } else {
+                let v = rng.range((-15).max(-1 - x), 15.min(40 - x));
+                writeln!(out, "addx {}", v).unwrap();
+                x += v;
+                cycles += 2;
+            }
+            ninstr += 1;
+        }
+        out
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+#[derive(Debug)]
+pub enum OpCode {
+    Noop,
+    AddX(i32),
+}
+
+fn in_sprite_off(sprite_mid: i32, offset: i32, curpixel: i32) -> bool {
+    let spritepos = sprite_mid + offset;
+    if (0..40).contains(&spritepos) {
+        return spritepos == curpixel;
+    }
+    false
+}
+
+fn in_sprite(sprite_mid: i32, curpixel: i32) -> bool {
+    in_sprite_off(sprite_mid, -1, curpixel) || 
+    in_sprite_off(sprite_mid, 0, curpixel) ||
+    in_sprite_off(sprite_mid, 1, curpixel)
+}
+
+fn parse_program(input: &str) -> Result<Vec<OpCode>> {
+    let lineit = input.lines().enumerate().take_while(|(_, lnstr)| {
+        !lnstr.is_empty()
+    }).map(|(i, lnstr)| -> Result<OpCode> {
+        let err = |part: &str, msg: String| Error::parse(Day10::DAY, i + 1, column_of(lnstr, part), msg);
+        let mut parts = lnstr.split(' ');
+        let opname = parts.next().unwrap_or(lnstr);
+        let opcode: OpCode;
+        if opname == "noop" {
+            opcode = OpCode::Noop;
+        } else if opname == "addx" {
+            let vstr = parts.next().ok_or_else(|| err(&lnstr[lnstr.len()..], String::from("expected operand of addx")))?;
+            let v = vstr.parse::<i32>().map_err(|e| err(vstr, format!("invalid operand '{}': {}", vstr, e)))?;
+            opcode = OpCode::AddX(v);
+        } else {
+            return Err(err(opname, format!("unknown opcode '{}'", opname)));
+        }
+        Ok(opcode)
+    });
+
+    let prog = lineit.collect::<Result<Vec<_>>>()?;
+    let mut exec: Vec<OpCode> = Vec::new();
+    for opc in prog {
+        if let OpCode::AddX(x) = opc {
+            exec.push(OpCode::Noop);
+            exec.push(OpCode::AddX(x));
+        } else {
+            exec.push(OpCode::Noop);
+        }
+    }
+    Ok(exec)
+}
+
+/// Runs the cycle-expanded program, returns the sum of the probed signal 
+/// strengths and the rendered CRT rows.
+fn run(exec: &[OpCode]) -> (i32, [[u8; 40]; 6]) {
+    let mut rx: i32 = 1;
+    let mut next_probe = 20;
+    let mut total_sig = 0;
+
+    let mut framebuf = [[b' '; 40]; 6];
+    let mut currow = 0;
+
+    // the CRT only shows the first 240 cycles
+    for (cycle, opc) in exec.iter().enumerate().take(40 * 6) {
+        if cycle + 1 == next_probe {
+            let signal = (cycle + 1) as i32 * rx;
+            if next_probe <= 220 {
+                total_sig += signal;
+            }
+            next_probe += 40;
+        }
+        
+        let curpixel: i32 = cycle as i32 % 40;
+        if in_sprite(rx, curpixel) {
+            framebuf[currow][curpixel as usize] = b'#';
+        } else {
+            framebuf[currow][curpixel as usize] = b'.';
+        }
+        if curpixel == 39 {
+            currow += 1;
+        }
+        
+        if let OpCode::AddX(x) = opc {
+            rx += x;
+        }
+    }
+    (total_sig, framebuf)
+}
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    const DAY: u8 = 10;
+    /// program expanded to one opcode per cycle
+    type Input = Vec<OpCode>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_program(input)
+    }
+
+    fn part1(exec: &Self::Input) -> i32 {
+        let (total_sig, _) = run(exec);
+        total_sig
+    }
+
+    /// CRT image, one line per row
+    fn part2(exec: &Self::Input) -> String {
+        let (_, framebuf) = run(exec);
+        let rows: Vec<String> = framebuf.iter().map(|row| String::from_utf8_lossy(row).into_owned()).collect();
+        rows.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 13140);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....");
+    }
+
+    #[test]
+    fn longer_than_the_screen() {
+        // the example takes exactly 240 cycles, later ones are not drawn
+        let input = Day10::parse(&format!("{}noop\naddx 5\n", EXAMPLE)).unwrap();
+        let example = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(input.len(), 243);
+        assert_eq!(Day10::part1(&input), Day10::part1(&example));
+        assert_eq!(Day10::part2(&input), Day10::part2(&example));
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day11;
+
+/// Distinct divisors keep the modulus of part 2 small enough that squaring a
+/// worry level below it cannot overflow.
+const DIVISORS: [usize; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+enum Op {
+    Add(usize),
+    Mul(usize),
+    Square,
+}
+
+struct Monkey {
+    items: Vec<usize>,
+    op: Op,
+    divisor: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+fn troop(rng: &mut Rng, n: usize) -> Vec<Monkey> {
+    let mut divisors = DIVISORS.to_vec();
+    rng.shuffle(&mut divisors);
+    // like the official inputs at most one monkey squares the worry level
+    let squaring = if n > 2 { Some(rng.below(n)) } else { None };
+    divisors.iter().take(n).enumerate().map(|(i, &divisor)| {
+        let items = (0..rng.range(1, 6)).map(|_| rng.range(50, 99) as usize).collect();
+        let op = if squaring == Some(i) {
+            Op::Square
+        } else if rng.chance(1, 2) {
+            Op::Mul(rng.range(2, 19) as usize)
+        } else {
+            Op::Add(rng.range(1, 8) as usize)
+        };
+        let mut others: Vec<usize> = (0..n).filter(|&j| j != i).collect();
+        rng.shuffle(&mut others);
+        Monkey { items, op, divisor, if_true: others[0], if_false: others[others.len() - 1] }
+    }).collect()
+}
+
+/// part 1 keeps the exact worry levels, squaring them round after round may
+/// overflow where the official inputs never do
+fn part1_fits(troop: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<usize>> = troop.iter().map(|m| m.items.clone()).collect();
+    for _round in 0..20 {
+        for (i, monkey) in troop.iter().enumerate() {
+            for old in std::mem::take(&mut items[i]) {
+                let new = match monkey.op {
+                    Op::Add(n) => old.checked_add(n),
+                    Op::Mul(n) => old.checked_mul(n),
+                    Op::Square => old.checked_mul(old),
+                };
+                let Some(new) = new.map(|w| w / 3) else {
+                    return false;
+                };
+                let target = if new.is_multiple_of(monkey.divisor) { monkey.if_true } else { monkey.if_false };
+                items[target].push(new);
+            }
+        }
+    }
+    true
+}
+
+impl Generate for Day11 {
+    /// `size` monkeys, between 2 and 9
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.clamp(2, DIVISORS.len());
+        let troop = loop {
+            let troop = troop(rng, n);
+            if part1_fits(&troop) {
+                break troop;
+            }
+        };
+
+        let mut out = String::new();
+        for (i, monkey) in troop.iter().enumerate() {
+            let items: Vec<String> = monkey.items.iter().map(|w| w.to_string()).collect();
+            let op = match monkey.op {
+                Op::Add(n) => format!("old + {}", n),
+                Op::Mul(n) => format!("old * {}", n),
+                Op::Square => String::from("old * old"),
+            };
+            if i > 0 {
+                out.push('\n');
+            }
+            writeln!(out, "Monkey {}:", i).unwrap();
+            writeln!(out, "  Starting items: {}", items.join(", ")).unwrap();
+            writeln!(out, "  Operation: new = {}", op).unwrap();
+            writeln!(out, "  Test: divisible by {}", monkey.divisor).unwrap();
+            writeln!(out, "    If true: throw to monkey {}", monkey.if_true).unwrap();
+            writeln!(out, "    If false: throw to monkey {}", monkey.if_false).unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+/// divisibility test deciding where a monkey throws an item
+type TestOp = Rc<dyn Fn(usize) -> bool>;
+
+/// Error about `part` of `line`, the `lineno`th line of the input.
+fn parse_error(line: &str, lineno: usize, part: &str, msg: impl Into<String>) -> Error {
+    Error::parse(Day11::DAY, lineno, column_of(line, part), msg)
+}
+
+fn strip_prefix<'a>(line: &'a str, lineno: usize, prefix: &str) -> Result<&'a str> {
+    let trimmed = line.trim();
+    trimmed.strip_prefix(prefix).ok_or_else(|| parse_error(line, lineno, trimmed, format!("expected line starting with '{}'", prefix)))
+}
+
+fn parse_num(line: &str, lineno: usize, num: &str) -> Result<usize> {
+    num.parse::<usize>().map_err(|e| parse_error(line, lineno, num, format!("invalid number '{}': {}", num, e)))
+}
+
+fn parse_starting_items(line: &str, lineno: usize) -> Result<VecDeque<usize>> {
+    let numbers = strip_prefix(line, lineno, "Starting items:")?;
+    
+    numbers.trim().split(", ").map(|num| parse_num(line, lineno, num)).collect::<Result<VecDeque<usize>>>()
+}
+
+fn parse_operation(line: &str, lineno: usize) -> Result<Rc<dyn Fn(usize) -> usize>> {
+    let eq = strip_prefix(line, lineno, "Operation: ")?;
+
+    let errf = || parse_error(line, lineno, eq, "could not parse equation, expected 'new = old <op> <operand>'");
+    let mut parts = eq.split(' ');
+    let is = parts.next().ok_or_else(errf)?;
+    if is != "new" {
+        return Err(parse_error(line, lineno, is, "left of = must be 'new'"));
+    }
+
+    let lhs = parts.nth(1).ok_or_else(errf)?;
+    if lhs != "old" {
+        return Err(parse_error(line, lineno, lhs, "left operand must be 'old'"));
+    }
+
+    let op = parts.next().ok_or_else(errf)?;
+    let rhs = parts.next().ok_or_else(errf)?;
+
+    if op == "*" {
+        if rhs == "old" {
+            let closure = |old: usize | { old * old };
+            return Ok(Rc::new(closure));
+        } else {
+            let c = parse_num(line, lineno, rhs)?;
+            let closure = move |old: usize| {old * c};
+            return Ok(Rc::new(closure));
+        }
+    } else if op == "+" {
+        if rhs == "old" {
+            let closure = |old: usize| { old + old };
+            return Ok(Rc::new(closure));
+        } else {
+            let c = parse_num(line, lineno, rhs)?;
+            let closure = move |old: usize| {old + c};
+            return Ok(Rc::new(closure));
+        }
+    }
+    Err(parse_error(line, lineno, op, format!("unknown operation '{}'", op)))
+}
+
+/// divisibility test and its divisor
+fn parse_test(line: &str, lineno: usize) -> Result<(TestOp, usize)> {
+    let cond = strip_prefix(line, lineno, "Test: ")?;
+
+    let val = cond.split(' ').nth(2).ok_or_else(|| parse_error(line, lineno, cond, "expected 'divisible by <n>'"))?;
+    let val = parse_num(line, lineno, val)?;
+    if val == 0 {
+        return Err(parse_error(line, lineno, cond, "divisor must not be 0"));
+    }
+    let closure = move |test: usize| -> bool { test.is_multiple_of(val) };
+    Ok((Rc::new(closure), val))
+}
+
+fn parse_true_target(line: &str, lineno: usize) -> Result<usize> {
+    let tar = strip_prefix(line, lineno, "If true: throw to monkey ")?;
+    parse_num(line, lineno, tar)
+}
+
+fn parse_false_target(line: &str, lineno: usize) -> Result<usize> {
+    let tar = strip_prefix(line, lineno, "If false: throw to monkey ")?;
+    parse_num(line, lineno, tar)
+}
+
+/// collective of monkeys == a troop of monkeys!
+#[derive(Clone)]
+pub struct MonkeyTroop {
+    items: Vec<VecDeque<usize>>,
+    op: Vec<Rc<dyn Fn(usize) -> usize>>,
+    test_op: Vec<TestOp>,
+    divisors: Vec<usize>,
+    true_target: Vec<usize>,
+    false_target: Vec<usize>,
+    inspected_items: Vec<usize>,
+}
+
+impl MonkeyTroop {
+    fn empty() -> Self {
+        Self {
+            items: Vec::new(),
+            op: Vec::new(),
+            test_op: Vec::new(),
+            divisors: Vec::new(),
+            true_target: Vec::new(),
+            false_target: Vec::new(),
+            inspected_items: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// worry levels can be taken modulo the product of all divisors without
+    /// changing the outcome of any test
+    pub fn modulus(&self) -> usize {
+        self.divisors.iter().product()
+    }
+}
+
+fn solve1(monkeys: &mut MonkeyTroop) -> usize {
+    for _round in 0..20 {
+        for i in 0..monkeys.len() {
+            let mut qi = monkeys.items[i].clone();
+            monkeys.inspected_items[i] += qi.len();
+            while !qi.is_empty() {
+                let olvl = qi.pop_front().expect("queue qi should be non-empty");
+                let nlvl = (monkeys.op[i])(olvl) / 3;
+                if (monkeys.test_op[i])(nlvl) {
+                    let ti = monkeys.true_target[i];
+                    monkeys.items[ti].push_back(nlvl);
+                } else {
+                    let fi = monkeys.false_target[i];
+                    monkeys.items[fi].push_back(nlvl);
+                }
+            }
+            monkeys.items[i] = qi;
+        }
+    }
+    let mut inspected = monkeys.inspected_items.clone();
+    inspected.sort();
+    inspected.reverse();
+    inspected[0] * inspected[1]
+}
+
+fn solve2(monkeys: &mut MonkeyTroop) -> usize {
+    let modulus = monkeys.modulus();
+    for _round in 0..10000 {
+        for i in 0..monkeys.len() {
+            let mut qi = monkeys.items[i].clone();
+            monkeys.inspected_items[i] += qi.len();
+            while !qi.is_empty() {
+                let olvl = qi.pop_front().expect("queue qi should be non-empty");
+                // normalize on every throw, an item may pass several monkeys in one round
+                let nlvl = (monkeys.op[i])(olvl) % modulus;
+                if (monkeys.test_op[i])(nlvl) {
+                    let ti = monkeys.true_target[i];
+                    monkeys.items[ti].push_back(nlvl);
+                } else {
+                    let fi = monkeys.false_target[i];
+                    monkeys.items[fi].push_back(nlvl);
+                }
+            }
+            monkeys.items[i] = qi;
+        }
+    }
+    let mut inspected = monkeys.inspected_items.clone();
+    inspected.sort();
+    inspected.reverse();
+    inspected[0] * inspected[1]
+}
+
+fn parse_troop(input: &str) -> Result<MonkeyTroop> {
+    let mut acc = MonkeyTroop::empty();
+    let lines: Vec<(&str, usize)> = input.lines().enumerate().map(|(i, line)| (line, i + 1)).collect();
+    // monkeys are separated by a blank line, the last one may lack it
+    for lines in lines.chunks(7) {
+        if lines.len() < 6 {
+            let (_, ln) = lines[lines.len() - 1];
+            return Err(Error::parse(Day11::DAY, ln + 1, 1, "incomplete monkey description"));
+        }
+        let (starting_items_str, ln) = lines[1];
+        let items = parse_starting_items(starting_items_str, ln)?;
+        let (operation_str, ln) = lines[2];
+        let op = parse_operation(operation_str, ln)?;
+        let (test_str, ln) = lines[3];
+        let (testop, divisor) = parse_test(test_str, ln)?;
+        let (true_tar_str, ln) = lines[4];
+        let true_tar = parse_true_target(true_tar_str, ln)?;
+        let (false_tar_str, ln) = lines[5];
+        let false_tar = parse_false_target(false_tar_str, ln)?;
+
+        acc.items.push(items);
+        acc.op.push(op);
+        acc.test_op.push(testop);
+        acc.divisors.push(divisor);
+        acc.true_target.push(true_tar);
+        acc.false_target.push(false_tar);
+        acc.inspected_items.push(0);
+    }
+
+    // monkey business is the product of the two most active monkeys
+    if acc.len() < 2 {
+        return Err(Error::parse(Day11::DAY, lines.len() + 1, 1, format!("expected at least two monkeys, found {}", acc.len())));
+    }
+    // throw targets have to name a monkey of the troop
+    for i in 0..acc.len() {
+        for (offset, target) in [(4, acc.true_target[i]), (5, acc.false_target[i])] {
+            if target >= acc.len() {
+                return Err(Error::parse(Day11::DAY, 7 * i + offset + 1, 1, format!("there is no monkey {} to throw to", target)));
+            }
+        }
+    }
+    Ok(acc)
+}
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    const DAY: u8 = 11;
+    type Input = MonkeyTroop;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_troop(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> usize {
+        solve1(&mut monkeys.clone())
+    }
+
+    fn part2(monkeys: &Self::Input) -> usize {
+        solve2(&mut monkeys.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 10605);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), 2713310158);
+    }
+
+    #[test]
+    fn squared_within_one_round() {
+        // every monkey squares the items and passes them on, so an item is
+        // squared up to five times in a round and must be reduced after
+        // each throw
+        let troop: String = [2, 3, 5, 7, 11].iter().enumerate().map(|(i, divisor)| {
+            format!("Monkey {i}:\n  Starting items: 99\n  Operation: new = old * old\n  Test: divisible by {divisor}\n    \
+                     If true: throw to monkey {next}\n    If false: throw to monkey {next}\n\n", next = (i + 1) % 5)
+        }).collect();
+        let input = Day11::parse(&troop).unwrap();
+        // the first round monkey i inspects i + 1 items, all five after it
+        assert_eq!(Day11::part2(&input), 50000 * 49999);
+    }
+
+    #[test]
+    fn too_few_monkeys() {
+        assert_eq!(Day11::parse("").err().unwrap().to_string(),
+                   "day 11 input line 1, column 1: expected at least two monkeys, found 0");
+        let one = EXAMPLE.split("\n\n").next().unwrap().replace("monkey 2", "monkey 0").replace("monkey 3", "monkey 0");
+        assert_eq!(Day11::parse(&one).err().unwrap().to_string(),
+                   "day 11 input line 7, column 1: expected at least two monkeys, found 1");
+    }
+}
@@ -0,0 +1,44 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day12;
+
+impl Generate for Day12 {
+    /// height map of `size` rows (at least 5) and twice as many columns (at
+    /// least 26), the best signal location is reachable from the start
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rows = size.max(5);
+        let cols = (2 * size).max(26);
+        let mut grid: Vec<Vec<u8>> = (0..rows).map(|_| {
+            (0..cols).map(|_| b'a' + rng.below(26) as u8).collect()
+        }).collect();
+
+        // walk from the left to the right edge, the height rises from a to z
+        // along the way by at most one per step
+        let (mut r, target) = (rng.below(rows), rng.below(rows));
+        let mut path = vec![(r, 0)];
+        let mut c = 0;
+        while c < cols - 1 || r != target {
+            if r != target && (c == cols - 1 || rng.chance(1, 3)) {
+                r = if r < target { r + 1 } else { r - 1 };
+            } else {
+                c += 1;
+            }
+            path.push((r, c));
+        }
+        let last = path.len() - 1;
+        for (k, &(r, c)) in path.iter().enumerate() {
+            grid[r][c] = b'a' + (k * 25 / last) as u8;
+        }
+        let (sr, sc) = path[0];
+        grid[sr][sc] = b'S';
+        grid[r][c] = b'E';
+
+        let mut out = String::with_capacity(rows * (cols + 1));
+        for row in grid {
+            out.push_str(&String::from_utf8(row).unwrap());
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -1,56 +1,60 @@
-use std::cmp::min;
-use std::{env, vec};
-use std::fs::File;
-use std::io::prelude::*;
-use std::collections::{HashMap};
+use std::collections::HashMap;
 use std::thread;
 use std::sync::{Arc, Mutex};
 
-use std::io::ErrorKind;
-type IoError = std::io::Error;
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
 
-type NodeId = usize;
+mod generate;
 
-struct Graph {
+pub type NodeId = usize;
+
+pub struct Graph {
     adjlist: HashMap<NodeId, Vec<NodeId>>,
     node_weights: HashMap<NodeId, i32>,
     nnodes: usize,
 }
 
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Graph {
-    fn new() -> Graph {
+    pub fn new() -> Graph {
         Self { adjlist: HashMap::new(), node_weights: HashMap::new(), nnodes: 0 }
     }
 
-    fn push_edge(&mut self, from: NodeId, to: NodeId) {
-        let handle = self.adjlist.entry(from).or_insert(Vec::new());
+    pub fn push_edge(&mut self, from: NodeId, to: NodeId) {
+        let handle = self.adjlist.entry(from).or_default();
         handle.push(to);
-        self.calc_node_cnt();
+        self.nnodes = self.nnodes.max(from.max(to) + 1);
     }
 
-    fn set_node_weight(&mut self, node: NodeId, weight: i32) {
+    pub fn set_node_weight(&mut self, node: NodeId, weight: i32) {
         self.node_weights.entry(node).and_modify(|w| *w = weight).or_insert(0);
     }
 
-    fn node_cnt(&self) -> usize {
+    pub fn node_cnt(&self) -> usize {
         self.nnodes
     }
 
-    fn calc_node_cnt(&mut self) -> usize {
+    pub fn calc_node_cnt(&mut self) -> usize {
         let mut max_id = 0;
-        self.adjlist.iter().for_each(|(_, list)| {
-            let m = list.iter().map(|node| *node).max().unwrap();
-            max_id = std::cmp::max(max_id, m);
+        self.adjlist.iter().for_each(|(from, list)| {
+            let m = list.iter().copied().max().unwrap_or(*from);
+            max_id = std::cmp::max(max_id, std::cmp::max(*from, m));
         });
         self.nnodes = max_id + 1;
         self.nnodes 
     }
 
-    fn neighbors(&self, node: NodeId) -> Option<&Vec<NodeId>> {
+    pub fn neighbors(&self, node: NodeId) -> Option<&Vec<NodeId>> {
         self.adjlist.get(&node)
     }
 
-    fn dijsktra(&self, start: NodeId, target: NodeId) -> i32 {
+    pub fn dijsktra(&self, start: NodeId, target: NodeId) -> i32 {
         let mut dist = vec![i32::MAX; self.node_cnt()];
         let mut done = vec![true; self.node_cnt()];
         for x in self.adjlist.keys() {
@@ -61,7 +65,7 @@ impl Graph {
         
         let next_node = |dist: &[i32], done: &[bool]| -> Option<NodeId> {
             let res = done.iter().enumerate().filter(|(_, flag)| {
-                **flag == false
+                !**flag
             }).min_by(|&(x, _), &(y, _)| -> std::cmp::Ordering {
                 if dist[x] < dist[y] {
                     return std::cmp::Ordering::Less;
@@ -95,11 +99,11 @@ impl Graph {
         dist[target]
     }
 
-    fn is_connected(&self, from: NodeId, to: NodeId) -> bool {
+    pub fn is_connected(&self, from: NodeId, to: NodeId) -> bool {
         let mut frontier = vec![from];
         let mut done = vec![false; self.node_cnt()];
 
-        while frontier.len() > 0 {
+        while !frontier.is_empty() {
             let mut new_frontier: Vec<NodeId> = Vec::with_capacity(frontier.len());
             for x in &frontier {
                 if done[*x] {
@@ -108,7 +112,7 @@ impl Graph {
                 done[*x] = true;
                 
                 for y in self.neighbors(*x).unwrap_or(&Vec::new()) {
-                    if done[*y] == true {
+                    if done[*y] {
                         continue;
                     }
                     if *y == to {
@@ -123,7 +127,7 @@ impl Graph {
         false
     }
 
-    fn bruteforce_assp(&self, source: NodeId, target: NodeId) -> i32 {
+    pub fn bruteforce_assp(&self, source: NodeId, target: NodeId) -> i32 {
         let initial_weight = *self.node_weights.get(&source).expect("each node with outgoing edges should have a weight");
         let mut best_dist = i32::MAX;
         for (s, sw) in self.node_weights.iter() {
@@ -133,13 +137,13 @@ impl Graph {
                 }
                 let ds = self.dijsktra(source, target);
                 best_dist = std::cmp::min(best_dist, ds);
-                println!("best distance after node {} is {}", s, best_dist);
+                // println!("best distance after node {} is {}", s, best_dist);
             }
         } 
         best_dist
     }
 
-    fn print(&self) {
+    pub fn print(&self) {
         println!("graph: node_cnt: {}", self.node_cnt());
         self.adjlist.iter().for_each(|(node, list)| {
             let w = self.node_weights.get(node).unwrap_or(&0);
@@ -148,7 +152,7 @@ impl Graph {
     }
 }
 
-struct GraphReader {
+pub struct GraphReader {
     buf: Vec<u8>,
     xlen: usize,
     ylen: usize,
@@ -157,14 +161,38 @@ struct GraphReader {
 }
 
 impl GraphReader {
-    fn new(file: &File) -> std::io::Result<Self> {
-        let mut buf: Vec<u8> = Vec::new();    
-        let mut reader = std::io::BufReader::new(file);
-        reader.read_to_end(&mut buf)?;
-        buf.push('\n' as u8);
-        let ylen = buf.iter().take_while(|x| **x != '\n' as u8).count();
-        let xlen = buf.iter().filter(|x| **x == '\n' as u8).count();
-        buf = buf.iter().filter(|x| **x != '\n' as u8).map(|&x| x).collect();
+    pub fn new(input: &str) -> Result<Self> {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut xlen = 0;
+        let mut ylen = 0;
+        let mut has_source = false;
+        let mut has_target = false;
+        for (i, line) in input.lines().enumerate() {
+            if let Some((col, c)) = line.chars().enumerate().find(|(_, c)| !matches!(c, 'a'..='z' | 'S' | 'E')) {
+                return Err(Error::parse(Day12::DAY, i + 1, col + 1, format!("invalid height '{}'", c)));
+            }
+            if xlen == 0 {
+                ylen = line.len();
+            } else if line.len() != ylen {
+                return Err(Error::parse(Day12::DAY, i + 1, 1, format!("expected row of length {}, found {}", ylen, line.len())));
+            }
+            for (col, c) in line.bytes().enumerate() {
+                let seen = match c {
+                    b'S' => &mut has_source,
+                    b'E' => &mut has_target,
+                    _ => continue,
+                };
+                if *seen {
+                    return Err(Error::parse(Day12::DAY, i + 1, col + 1, format!("duplicate '{}'", c as char)));
+                }
+                *seen = true;
+            }
+            buf.extend_from_slice(line.as_bytes());
+            xlen += 1;
+        }
+        if !has_source || !has_target {
+            return Err(Error::parse(Day12::DAY, xlen.max(1), 1, "height map needs a start 'S' and a target 'E'"));
+        }
         Ok(Self { buf, xlen, ylen, source: 0, target: 0 })
     }
 
@@ -204,21 +232,21 @@ impl GraphReader {
         let mut cfrom = self.buf[from];
         let mut cto = self.buf[to];
         
-        if cfrom == 'S' as u8 {
-            cfrom = 'a' as u8;
+        if cfrom == b'S' {
+            cfrom = b'a';
         }
-        if cto == 'E' as u8 {
-            cto = 'z' as u8;
+        if cto == b'E' {
+            cto = b'z';
         }
-        cfrom > cto || cfrom == cto || cfrom + 1 == cto
+        cfrom >= cto || cfrom + 1 == cto
     }
 
     fn node_weight(&self, node: NodeId) -> i32 {
         let cn = self.buf[node];
-        if cn == 'S' as u8 {
+        if cn == b'S' {
             return 'a' as i32;
         } 
-        if cn == 'E' as u8 {
+        if cn == b'E' {
             return 'z' as i32;
         }
         cn as i32
@@ -231,14 +259,14 @@ impl GraphReader {
         }
     }
 
-    fn read(&mut self) -> (Graph, NodeId, NodeId) {
+    pub fn read(&mut self) -> (Graph, NodeId, NodeId) {
         let mut graph = Graph::new();
         for x in 0..self.xlen {
             for y in 0..self.ylen {
                 let from = self.as_nid(x, y);
-                if self.buf[from] == 'S' as u8 {
+                if self.buf[from] == b'S' {
                     self.source = from;
-                } else if self.buf[from] == 'E' as u8 {
+                } else if self.buf[from] == b'E' {
                     self.target = from;
                 }
 
@@ -256,12 +284,14 @@ impl GraphReader {
                 }
             }
         }
+        // cells without any passable neighbour are still nodes
+        graph.nnodes = graph.nnodes.max(self.xlen * self.ylen);
         (graph, self.source, self.target)
     }
 }
 
 
-fn multithread_assp<const NTHREADS: usize>(graph: Arc<Graph>, source: NodeId, target: NodeId) -> std::thread::Result<i32> {
+pub fn multithread_assp<const NTHREADS: usize>(graph: Arc<Graph>, source: NodeId, target: NodeId) -> std::thread::Result<i32> {
     let initial_weight = *graph.node_weights.get(&source).expect("each node with outgoing edges should have a weight");
     let start_set: Vec<NodeId> = graph.node_weights.iter()
                                       .filter(|(_, xw)| **xw == initial_weight)
@@ -289,7 +319,7 @@ fn multithread_assp<const NTHREADS: usize>(graph: Arc<Graph>, source: NodeId, ta
                 
                 if graph.is_connected(node, target) {
                     let dist = graph.dijsktra(node, target);
-                    println!("found dist {} for start node {}", dist, node);
+                    // println!("found dist {} for start node {}", dist, node);
                     let mut results = results.lock().unwrap();
                     results.push(dist);
                 }
@@ -306,30 +336,64 @@ fn multithread_assp<const NTHREADS: usize>(graph: Arc<Graph>, source: NodeId, ta
     Ok(*rs.iter().min().unwrap())
 }
 
-fn main() -> std::io::Result<()> {
-    let argv: Vec<String> = env::args().collect();
-    if argv.len() < 2 {
-        return Err(IoError::new(ErrorKind::InvalidInput, "Expected filename as argument"));
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    const DAY: u8 = 12;
+    /// height map graph with start and best signal location
+    type Input = (Arc<Graph>, NodeId, NodeId);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut reader = GraphReader::new(input)?;
+        let (graph, source, target) = reader.read();
+        if !graph.is_connected(source, target) {
+            let (line, column) = (target / reader.ylen + 1, target % reader.ylen + 1);
+            return Err(Error::parse(Day12::DAY, line, column, "the target 'E' cannot be reached from the start 'S'"));
+        }
+        Ok((Arc::new(graph), source, target))
     }
-    let file = File::open(&argv[1])?;
-    let (graph, source, target) = GraphReader::new(&file)?.read();
 
-    // g.print();
-    println!("read graph start algo..");
+    fn part1((graph, source, target): &Self::Input) -> i32 {
+        graph.dijsktra(*source, *target)
+    }
 
-    // println!("{} and {} are connected? {}", g.source, g.drain, g.is_connected(g.source, g.drain));
+    fn part2((graph, source, target): &Self::Input) -> i32 {
+        // let d2 = graph.bruteforce_assp(source, target);
+        multithread_assp::<64>(graph.clone(), *source, *target).expect("thread error")
+    }
+}
 
-    // let d1 = graph.dijsktra(source, target);
-    // println!("solution 1: dist {} to {} is {}", source, target, d1);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // let d2 = graph.bruteforce_assp(source, target);
-    // println!("solution 2: shortest overall distance is {}", d2);
-    
-    let graphptr = Arc::new(graph);
-    if let Ok(d2) = multithread_assp::<64>(graphptr, source, target) {
-        println!("solution 2: shortes overall distance is {}", d2);
-    } else {
-        eprintln!("thread error");
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 31);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 29);
     }
-    Ok(())
-}
\ No newline at end of file
+
+    #[test]
+    fn last_cell_without_incoming_edges() {
+        // the 'c' in the corner can only be left, no edge leads to it
+        let input = Day12::parse("Sbcdefghijklmnopqrstuvwxya\naaaaaaaaaaaaaaaaaaaaaaaaEc").unwrap();
+        assert_eq!(Day12::part1(&input), 25);
+        assert_eq!(Day12::part2(&input), 25);
+    }
+
+    #[test]
+    fn unreachable_target() {
+        assert_eq!(Day12::parse("Sbc\ndeE").err().unwrap().to_string(),
+                   "day 12 input line 2, column 3: the target 'E' cannot be reached from the start 'S'");
+    }
+}
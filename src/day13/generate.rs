@@ -0,0 +1,25 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day13;
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let elems: Vec<String> = (0..rng.range(0, 5)).map(|_| {
+        if depth < 4 && rng.chance(1, 3) {
+            packet(rng, depth + 1)
+        } else {
+            rng.range(0, 10).to_string()
+        }
+    }).collect();
+    format!("[{}]", elems.join(","))
+}
+
+impl Generate for Day13 {
+    /// `size` pairs of packets
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1)).map(|_| {
+            format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))
+        }).collect();
+        pairs.join("\n")
+    }
+}
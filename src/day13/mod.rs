@@ -0,0 +1,239 @@
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+const LIST_BEGIN: u8 = b'[';
+const LIST_END: u8 = b']';
+const SEP: u8 = b',';
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expr {
+    Nil,
+    Atom(u32),
+    List(Vec<Expr>)
+}
+
+/// parsed value and remaining input, or the input at which parsing failed
+type ParseResult<'a, T> = std::result::Result<(T, &'a [u8]), &'a [u8]>;
+
+fn parse_atom(xs: &[u8]) -> ParseResult<'_, Expr> {
+    let mut offset = 0;
+    for &x in xs {
+        if x == SEP || x == LIST_END || x == LIST_BEGIN {
+            break;
+        }
+        offset += 1;
+    }
+    if offset == 0 {
+        return Ok((Expr::Nil, xs));
+    }
+    let n = std::str::from_utf8(&xs[0..offset]).ok().and_then(|s| s.parse::<u32>().ok()).ok_or(xs)?;
+    if offset < xs.len() && xs[offset] == SEP {
+        offset += 1;
+    }
+    Ok((Expr::Atom(n), &xs[offset..]))
+}
+
+fn parse_atoms(xs: &[u8]) -> ParseResult<'_, Vec<Expr>> {
+    let mut atoms: Vec<Expr> = Vec::new();
+    let mut cursor = xs;
+    loop {
+        // println!("parser at {}", String::from_utf8_lossy(cursor));
+        let (atom, ys) = parse_atom(cursor)?;
+        if atom == Expr::Nil {
+            break;
+        }
+        atoms.push(atom);
+        cursor = ys;
+    }
+    Ok((atoms, cursor))
+}
+
+pub fn parse_list(xs: &[u8]) -> ParseResult<'_, Expr> {
+    let mut exprs: Vec<Expr> = Vec::new();
+    let mut cursor = xs;
+
+    if cursor.first() != Some(&LIST_BEGIN) {
+        return Err(cursor);
+    }
+    cursor = &cursor[1..];
+
+    loop {
+        if cursor.is_empty() {
+            // unterminated list
+            return Err(cursor);
+        }
+        // println!("cursor at {}", String::from_utf8_lossy(cursor));
+
+        if cursor[0] == LIST_END {
+            cursor = &cursor[1..];
+            if cursor.len() > 1 && cursor[0] == SEP {
+                cursor = &cursor[1..];
+            }
+            break;
+        }
+        if cursor[0] == LIST_BEGIN {
+            let (ls, xs) = parse_list(cursor)?;
+            exprs.push(ls);
+            cursor = xs;
+        } else {
+            let (mut atoms, xs) = parse_atoms(cursor)?;
+            if xs.len() == cursor.len() {
+                // e.g. a separator without a value before it
+                return Err(cursor);
+            }
+            exprs.append(&mut atoms);
+            cursor = xs;
+        }
+    }
+    Ok((Expr::List(exprs), cursor))
+}
+
+/// Parses a whole line as a single packet.
+fn parse_packet(line: &str, lineno: usize) -> Result<Expr> {
+    let column = |rest: &[u8]| line.len() - rest.len() + 1;
+    let (expr, rest) = parse_list(line.as_bytes()).map_err(|rest| {
+        let msg = match rest.first() {
+            Some(c) => format!("unexpected '{}'", *c as char),
+            None => String::from("unexpected end of packet"),
+        };
+        Error::parse(Day13::DAY, lineno, column(rest), msg)
+    })?;
+    if !rest.is_empty() {
+        return Err(Error::parse(Day13::DAY, lineno, column(rest), "trailing characters after packet"));
+    }
+    Ok(expr)
+}
+
+impl PartialOrd for Expr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Expr::Atom(x), Expr::Atom(y)) => x.partial_cmp(y),
+            (Expr::Atom(x), Expr::List(_)) => {
+                let lhs = Expr::List(vec![Expr::Atom(*x)]);
+                lhs.partial_cmp(other)
+            }
+            (Expr::List(_), Expr::Atom(y)) => {
+                let rhs = Expr::List(vec![Expr::Atom(*y)]);
+                self.partial_cmp(&rhs)
+            }
+            (Expr::List(xs), Expr::List(ys)) => {
+                let slen = std::cmp::min(xs.len(), ys.len());
+                for i in 0..slen {
+                    let r = xs[i].partial_cmp(&ys[i]).unwrap();
+                    if r != std::cmp::Ordering::Equal {
+                        return Some(r);
+                    }
+                }
+                Some(xs.len().cmp(&ys.len()))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn solve2(packets: &[Expr]) -> usize {
+    let mut exprs = packets.to_vec();
+
+    let (e1, _) = parse_list(b"[[2]]").unwrap();
+    let (e2, _) = parse_list(b"[[6]]").unwrap();
+    exprs.push(e1.clone());
+    exprs.push(e2.clone());
+    exprs.sort_by(|e1, e2| e1.partial_cmp(e2).unwrap());
+
+    // for expr in exprs {
+    //     println!("{:?}", expr);
+    // }
+
+    let mut idx1 = 1;
+    let mut idx2 = 1;
+    for (i, e) in exprs.iter().enumerate() {
+        let o = e.partial_cmp(&e1).unwrap();
+        if o == std::cmp::Ordering::Equal {
+            idx1 += i;
+        }
+        let o = e.partial_cmp(&e2).unwrap();
+        if o == std::cmp::Ordering::Equal {
+            idx2 += i;
+        }
+    }
+    idx1 * idx2
+}
+
+fn solve1(packets: &[Expr]) -> usize {
+    let mut cnt = 0;
+    for (i, pair) in packets.chunks(2).enumerate() {
+        let o = pair[0].partial_cmp(&pair[1]).unwrap();
+        // println!("---> {:?}", o);
+        if o == std::cmp::Ordering::Less {
+            cnt += i + 1;
+        }
+    }
+    cnt
+}
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    const DAY: u8 = 13;
+    /// all packets in input order, consecutive packets form a pair
+    type Input = Vec<Expr>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut exprs: Vec<Expr> = Vec::new();
+        let mut lineno = 0;
+        for (i, line) in input.lines().enumerate() {
+            lineno = i + 1;
+            // pairs are separated by a blank line
+            if i % 3 == 2 {
+                if !line.is_empty() {
+                    return Err(Error::parse(Self::DAY, lineno, 1, "expected blank line between packet pairs"));
+                }
+                continue;
+            }
+            exprs.push(parse_packet(line, lineno)?);
+        }
+        if exprs.len() % 2 == 1 {
+            return Err(Error::parse(Self::DAY, lineno + 1, 1, "last packet has no partner"));
+        }
+        Ok(exprs)
+    }
+
+    fn part1(packets: &Self::Input) -> usize {
+        solve1(packets)
+    }
+
+    fn part2(packets: &Self::Input) -> usize {
+        solve2(packets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), 140);
+    }
+
+    #[test]
+    fn missing_values() {
+        let error = |packet: &str| parse_packet(packet, 1).unwrap_err().to_string();
+        assert_eq!(error("[,1]"), "day 13 input line 1, column 2: unexpected ','");
+        assert_eq!(error("[1,,2]"), "day 13 input line 1, column 4: unexpected ','");
+        assert_eq!(error("[[1],,2]"), "day 13 input line 1, column 6: unexpected ','");
+    }
+}
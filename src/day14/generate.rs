@@ -0,0 +1,31 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day14;
+
+impl Generate for Day14 {
+    /// `size` rock paths of 2 to 5 points at least 10 below the sand source,
+    /// the cave is `20 + size` deep up to a depth of 170
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let depth = (20 + size as i64).min(170);
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let (mut x, mut y) = (rng.range(450, 550), rng.range(10, depth));
+            let mut points = vec![format!("{},{}", x, y)];
+            let horizontal = rng.chance(1, 2);
+            for k in 0..rng.range(1, 4) {
+                // alternate between horizontal and vertical lines
+                if (k % 2 == 0) == horizontal {
+                    x = (x + rng.range(-8, 8)).clamp(400, 600);
+                } else {
+                    y = (y + rng.range(-8, 8)).clamp(10, depth);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            writeln!(out, "{}", points.join(" -> ")).unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+pub type Point = (u32, u32);
+
+#[derive(Debug, Clone, Default)]
+pub struct LaneMap {
+    lanes: HashMap<u32, HashSet<u32>>, 
+    y_max: u32,
+}
+
+impl LaneMap {
+    pub fn new() -> Self {
+        Self { lanes: HashMap::new(), y_max: 0 }
+    }
+
+    pub fn insert(&mut self, p: Point, set_floor: bool) -> bool {
+        let (x, y) = p;
+        let s = self.lanes.entry(x).or_default();
+        if set_floor {
+            self.y_max = std::cmp::max(self.y_max, y);
+        }
+        s.insert(y)
+    }
+
+    pub fn floor(&self) -> u32 {
+        self.y_max + 2
+    }
+
+    pub fn lane_hit(&self, p0: Point) -> Option<Point> {
+        let (x0, y0) = p0;
+        if let Some(lane) = self.lanes.get(&x0) {
+            if let Some(y) = lane.iter().filter(|&y1| y0 <= *y1).copied().min() {
+                return Some((x0, y));
+            }
+        }
+        None
+    }
+
+    pub fn floor_hit(&self, p0: Point) -> Point {
+        let (x0, _) = p0;
+        if let Some(p) = self.lane_hit(p0) {
+            return p;
+        }
+        (x0, self.floor()) 
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        let (x, y) = p;
+        self.lanes.contains_key(&x) && self.lanes.get(&x).unwrap().contains(&y)
+    }
+
+    pub fn insert_line(&mut self, p0: Point, p1: Point) {
+        let (x0, y0) = p0;
+        let (x1, y1) = p1;
+        for dx in 0..(1 + std::cmp::max(x0, x1) - std::cmp::min(x0, x1)) {
+            for dy in 0..(1 + std::cmp::max(y0, y1) - std::cmp::min(y0, y1)) {
+                let p = (std::cmp::min(x0, x1) + dx, std::cmp::min(y0, y1) + dy);
+                self.insert(p, true);
+            }
+        }
+    }
+
+}
+
+fn parse_pt(line: &str, lineno: usize, ptstr: &str) -> Result<Point> {
+    let err = |part: &str, msg: String| Error::parse(Day14::DAY, lineno, column_of(line, part), msg);
+    let (xstr, ystr) = ptstr.split_once(',').ok_or_else(|| err(ptstr, format!("expected point 'x,y', found '{}'", ptstr)))?;
+    let x = xstr.parse::<u32>().map_err(|e| err(xstr, format!("invalid x coordinate '{}': {}", xstr, e)))?;
+    let y = ystr.parse::<u32>().map_err(|e| err(ystr, format!("invalid y coordinate '{}': {}", ystr, e)))?;
+    Ok((x, y))
+}
+
+pub fn parse(input: &str) -> Result<LaneMap> {
+    let mut lmap = LaneMap::new();
+    for (i, lstr) in input.lines().enumerate() {
+        let parts: Vec<&str> = lstr.split(" -> ").collect();
+        let pts: Vec<Point> = parts.iter().map(|part| parse_pt(lstr, i + 1, part)).collect::<Result<_>>()?;
+        for (j, pair) in pts.windows(2).enumerate() {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            if x0 != x1 && y0 != y1 {
+                return Err(Error::parse(Day14::DAY, i + 1, column_of(lstr, parts[j + 1]), "rock lines have to be horizontal or vertical"));
+            }
+            lmap.insert_line(pair[0], pair[1]);
+        }
+    }
+    Ok(lmap)
+}
+
+fn trace_sand_unit(lmap: &LaneMap, p0: Point) -> Option<Point> {
+    if lmap.contains(p0) {
+        // the source is blocked before sand flows into the abyss
+        return None;
+    }
+    if let Some((x1, y1)) = lmap.lane_hit(p0) {
+        if !lmap.contains((x1 - 1, y1)) {
+            return trace_sand_unit(lmap, (x1 - 1, y1));
+        } else if !lmap.contains((x1 + 1, y1)) {
+            return trace_sand_unit(lmap, (x1 + 1, y1));
+        }
+        return Some((x1, y1));
+    }
+    None
+}
+
+fn trace_sand_unit2(lmap: &LaneMap, p0: Point) -> Option<Point> {
+    if lmap.contains(p0) {
+        return None;
+    }
+    if let Some((x1, y1)) = lmap.lane_hit(p0) {
+        if !lmap.contains((x1 - 1, y1)) {
+            return trace_sand_unit2(lmap, (x1 - 1, y1));
+        } else if !lmap.contains((x1 + 1, y1)) {
+            return trace_sand_unit2(lmap, (x1 + 1, y1));
+        }
+        return Some((x1, y1));
+    }
+    Some(lmap.floor_hit(p0))
+}
+
+fn solve2(lmap: &LaneMap) -> usize {
+    let mut lmap = lmap.clone();
+    let mut cnt = 0;
+    while let Some((x, y)) = trace_sand_unit2(&lmap, (500, 0)) {
+        lmap.insert((x, y - 1), false);
+        cnt += 1;
+    }
+    cnt
+}
+
+fn solve1(lmap: &LaneMap) -> usize {
+    let mut lmap = lmap.clone();
+    let mut cnt = 0;
+    while let Some((x, y)) = trace_sand_unit(&lmap, (500, 0)) {
+        lmap.insert((x, y - 1), false);
+        cnt += 1;
+    }
+    cnt
+}
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    const DAY: u8 = 14;
+    type Input = LaneMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    /// units of sand that come to rest before sand flows into the abyss
+    fn part1(lmap: &Self::Input) -> usize {
+        solve1(lmap)
+    }
+
+    /// units of sand that come to rest until the source is blocked
+    fn part2(lmap: &Self::Input) -> usize {
+        solve2(lmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input), 24);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&input), 93);
+    }
+
+    #[test]
+    fn source_blocked() {
+        // a closed cup around the source, no sand reaches the abyss
+        let input = Day14::parse("498,0 -> 498,3 -> 502,3 -> 502,0").unwrap();
+        assert_eq!(Day14::part1(&input), 7);
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day15;
+
+/// Search area of part 2.
+const BOUND: i64 = 4_000_000;
+
+/// Random position at distance `r` from `p`.
+fn on_border(rng: &mut Rng, p: (i64, i64), r: i64) -> (i64, i64) {
+    let dx = rng.range(-r, r);
+    let dy = r - dx.abs();
+    (p.0 + dx, if rng.chance(1, 2) { p.1 + dy } else { p.1 - dy })
+}
+
+impl Generate for Day15 {
+    /// `size` sensors within the search area plus four far away sensors,
+    /// exactly one position of the search area is not covered by any sensor
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut gap = (rng.range(0, BOUND), rng.range(0, BOUND));
+        // a gap on the row of part 1 would split its coverage
+        while gap.1 == BOUND / 2 {
+            gap.1 = rng.range(0, BOUND);
+        }
+
+        // a sensor at gap + (a, b) with a, b > BOUND whose range ends right
+        // before the gap covers every position of the search area in its
+        // quadrant around the gap, four of them cover all but the gap
+        let mut sensors: Vec<((i64, i64), i64)> = Vec::new();
+        for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let (a, b) = (rng.range(BOUND + 1, BOUND + BOUND / 8), rng.range(BOUND + 1, BOUND + BOUND / 8));
+            sensors.push(((gap.0 + sx * a, gap.1 + sy * b), a + b - 1));
+        }
+        while sensors.len() < size + 4 {
+            let s = (rng.range(0, BOUND), rng.range(0, BOUND));
+            let dist = (s.0 - gap.0).abs() + (s.1 - gap.1).abs();
+            if dist == 0 {
+                continue;
+            }
+            sensors.push((s, rng.range(0, dist - 1)));
+        }
+        rng.shuffle(&mut sensors);
+
+        let mut out = String::new();
+        for (s, r) in sensors {
+            let b = on_border(rng, s, r);
+            writeln!(out, "Sensor at x={}, y={}: closest beacon is at x={}, y={}", s.0, s.1, b.0, b.1).unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,184 @@
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+pub type Point = (i32, i32);
+pub type Interval = (i32, i32);
+
+fn mdist(p: Point, q: Point) -> i32 {
+    (p.0 - q.0).abs() + (p.1 - q.1).abs()
+}
+
+// requires fst < snd
+fn merge(fst: Interval, snd: Interval) -> Option<Interval> {
+    // adjacent intervals leave no hole between them either
+    if snd.0 <= fst.1 + 1 {
+        return Some((std::cmp::min(fst.0, snd.0), std::cmp::max(fst.1, snd.1)));
+    }
+    None
+}
+
+#[derive(Debug)]
+pub struct Cover {
+    pub signal: Point,
+    pub mrange: i32,
+}
+
+impl Cover {
+    pub fn new(signal: Point, beacon: Point) -> Self {
+        Self { signal, mrange: mdist(signal, beacon)}
+    }
+}
+
+/// Parses the number right after the next `key` in `curr`, returns the rest of the line after it.
+fn parse_assigned<'a>(line: &str, lineno: usize, curr: &'a str, key: &str) -> Result<(i32, &'a str)> {
+    let err = |part: &str, msg: String| Error::parse(Day15::DAY, lineno, column_of(line, part), msg);
+    let pos = curr.find(key).ok_or_else(|| err(curr, format!("expected '{}'", key)))?;
+    let curr = &curr[pos + key.len()..];
+    let start: usize;
+    let sign: i32;
+    if curr.chars().next().eq(&Some('-')) {
+        start = 1;
+        sign = -1;
+    } else {
+        start = 0;
+        sign = 1;
+    }
+    let off = curr[start..].chars().take_while(|c| c.is_ascii_digit()).count();
+    curr[start..start + off].parse::<i32>()
+                            .map_err(|e| err(curr, format!("invalid coordinate after '{}': {}", key, e)))
+                            .map(|res| (sign * res, &curr[start + off..]))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Cover>> {
+    let covers: Vec<Cover> = input.lines().enumerate().map(|(i, lstr)| -> Result<_> {
+        let (sx, curr) = parse_assigned(lstr, i + 1, lstr, "x=")?;
+        let (sy, curr) = parse_assigned(lstr, i + 1, curr, "y=")?;
+        let (bx, curr) = parse_assigned(lstr, i + 1, curr, "x=")?;
+        let (by, _) = parse_assigned(lstr, i + 1, curr, "y=")?;
+        Ok(Cover::new((sx, sy), (bx, by)))
+    }).collect::<Result<_>>()?;
+    if covers.is_empty() {
+        return Err(Error::parse(Day15::DAY, 1, 1, "expected at least one sensor"));
+    }
+    Ok(covers)
+}
+
+pub fn row_cover(y_target: i32, covers: &[Cover]) -> Vec<Interval> {
+    let mut ivals: Vec<Interval> = covers.iter()
+        .filter(|&cv| (cv.signal.1 - y_target).abs() <= cv.mrange)
+        .map(|cv| {
+            let delta = cv.mrange - (cv.signal.1 - y_target).abs();
+            let s = cv.signal.0 - delta;
+            let e = cv.signal.0 + delta;
+            (s, e) 
+        }).collect();
+    
+    ivals.sort_by(|(x0b, x0e), (x1b, x1e)| {
+        let o = x0b.partial_cmp(x1b).unwrap();
+        if o == std::cmp::Ordering::Equal {
+            return x0e.partial_cmp(x1e).unwrap();
+        }
+        o
+    });
+
+    // merge overlapping intervals in a single sweep over the sorted list
+    let mut merged: Vec<Interval> = Vec::new();
+    for iv in ivals {
+        if let Some(last) = merged.last_mut() {
+            if let Some(m) = merge(*last, iv) {
+                *last = m;
+                continue;
+            }
+        }
+        merged.push(iv);
+    }
+    merged
+}
+
+/// Scans the rows `0..=bound` for the single position not covered by any sensor.
+pub fn solve2(covers: &[Cover], bound: i32) -> usize {
+    let mut with_hole: Vec<Interval> = Vec::new();
+    let mut ycoord = 0;
+    for y in 0..=bound {
+        let ivals = row_cover(y, covers);
+        if ivals.len() > 1 {
+            // println!("y = {} --> {:?}", y, ivals);
+            with_hole = ivals;
+            ycoord = y;
+        }
+    }
+    let xcoord = with_hole[0].1 + 1;
+    // println!("x = {} y = {}", xcoord, ycoord);
+    (xcoord as usize) * 4000000 + (ycoord as usize)
+}
+
+pub fn solve1(covers: &[Cover], y_target: i32) -> i32 {
+    let ivals = row_cover(y_target, covers);
+    (ivals[0].1 - ivals[0].0).abs()
+}
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Cover>;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(covers: &Self::Input) -> i32 {
+        solve1(covers, 2000000)
+    }
+
+    fn part2(covers: &Self::Input) -> usize {
+        solve2(covers, 4000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    // the example asks about row 10 and a search area of 0..=20 instead of
+    // row 2000000 and 0..=4000000
+
+    #[test]
+    fn example_part1() {
+        let covers = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(solve1(&covers, 10), 26);
+    }
+
+    #[test]
+    fn example_part2() {
+        let covers = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&covers, 20), 56000011);
+    }
+
+    #[test]
+    fn merged_row_cover() {
+        let on_row = |xs: &[i32]| xs.iter().map(|&x| Cover { signal: (x, 0), mrange: 2 }).collect::<Vec<_>>();
+        // 2-6 and 6-10 overlap, 11-13 is a hole before 14-18
+        assert_eq!(row_cover(0, &on_row(&[4, 8, 16])), vec![(2, 10), (14, 18)]);
+        // 0-4 and 5-9 are adjacent, no hole
+        assert_eq!(row_cover(0, &on_row(&[2, 7])), vec![(0, 9)]);
+    }
+
+    #[test]
+    fn hole_in_last_row() {
+        // the distress beacon of the example is at 14,11
+        let covers = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(solve2(&covers, 11), 56000011);
+    }
+
+    #[test]
+    fn no_sensors() {
+        assert_eq!(Day15::parse("").unwrap_err().to_string(), "day 15 input line 1, column 1: expected at least one sensor");
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day16;
+
+/// Valves with a working flow rate, like in the official inputs. The search
+/// is exponential in their number.
+const MAX_WORKING: usize = 15;
+
+impl Generate for Day16 {
+    /// `size` connected valves (2 to 676) including AA, at most 15 of them
+    /// have a working flow rate
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.clamp(2, 26 * 26);
+        let mut names: Vec<String> = (0..26 * 26)
+            .map(|i| format!("{}{}", (b'A' + (i / 26) as u8) as char, (b'A' + (i % 26) as u8) as char))
+            .filter(|name| name != "AA")
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(n - 1);
+        names.insert(0, String::from("AA"));
+
+        // random spanning tree plus some shortcuts, tunnels go both ways
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut connect = |i: usize, j: usize| {
+            if i != j && !tunnels[i].contains(&j) {
+                tunnels[i].push(j);
+                tunnels[j].push(i);
+            }
+        };
+        for i in 1..n {
+            connect(i, rng.below(i));
+        }
+        for _ in 0..n / 2 {
+            connect(rng.below(n), rng.below(n));
+        }
+
+        let mut rates = vec![0; n];
+        let mut working: Vec<usize> = (1..n).collect();
+        rng.shuffle(&mut working);
+        for &v in working.iter().take(MAX_WORKING.min(n.div_ceil(4))) {
+            rates[v] = rng.range(1, 25);
+        }
+
+        let mut out = String::new();
+        for v in 0..n {
+            let to: Vec<&str> = tunnels[v].iter().map(|&w| names[w].as_str()).collect();
+            if to.len() == 1 {
+                writeln!(out, "Valve {} has flow rate={}; tunnel leads to valve {}", names[v], rates[v], to[0]).unwrap();
+            } else {
+                writeln!(out, "Valve {} has flow rate={}; tunnels lead to valves {}", names[v], rates[v], to.join(", ")).unwrap();
+            }
+        }
+        out
+    }
+}
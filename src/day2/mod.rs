@@ -0,0 +1,223 @@
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+use crate::runner::{self, DayAnswers, Part};
+
+mod game;
+mod generate;
+mod log;
+mod whatif;
+
+pub use game::{Game, Outcome, Weapon};
+pub use log::{rounds, Play, Round};
+pub use whatif::{analyze, Analysis, Interpretation, Reading};
+
+fn letter_list(letters: &[char]) -> String {
+    letters.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// strategy guide of a game
+///
+/// part 1 reads the second column letters as shapes and part 2 as desired
+/// outcomes, a letter only needs a meaning in the reading of the part that
+/// is solved, see [`Guide::check`]
+pub struct Guide {
+    pub game: Game,
+    /// (opponent weapon, second column) per round
+    pub rounds: Vec<(usize, char)>,
+}
+
+impl Guide {
+    pub fn parse(game: Game, input: &str) -> Result<Self> {
+        let mut rounds = Vec::new();
+        for (i, lstr) in input.lines().enumerate() {
+            let err = |column, msg: &str| Error::parse(Day2::DAY, i + 1, column, msg);
+            let cs: Vec<char> = lstr.chars().collect();
+            if cs.len() != 3 || cs[1] != ' ' {
+                return Err(err(1, "expected a round of the form 'A X'"));
+            }
+            let opponent = game.opponent(cs[0]).ok_or_else(|| {
+                err(1, &format!("opponent shape must be one of {}", letter_list(&game.opponent_letters())))
+            })?;
+            let second = cs[2];
+            if game.shape(second).is_none() && game.desired(second).is_none() {
+                return Err(err(3, &format!("second column must be one of {}", letter_list(&game.second_letters()))));
+            }
+            rounds.push((opponent, second));
+        }
+        Ok(Guide { game, rounds })
+    }
+
+    /// fails on the first round whose second column has no meaning in the
+    /// reading of `part`
+    pub fn check(&self, part: Part) -> Result<()> {
+        let (reading, letters) = match part {
+            Part::One => ("a shape", self.game.shape_letters()),
+            Part::Two => ("an outcome", self.game.outcome_letters().to_vec()),
+        };
+        match self.rounds.iter().position(|(_, c)| !letters.contains(c)) {
+            Some(i) => {
+                let msg = format!("part {} reads the second column as {}, '{}' is not one of {}",
+                                  part.number(), reading, self.rounds[i].1, letter_list(&letters));
+                Err(Error::parse(Day2::DAY, i + 1, 3, msg))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// second column is the shape to play, panics unless `check(Part::One)`
+    /// passed
+    pub fn score_as_shapes(&self) -> u32 {
+        self.rounds.iter().map(|&(op, c)| {
+            let me = self.game.shape(c).expect("checked by Guide::check");
+            self.game.round_score(me, op)
+        }).sum()
+    }
+
+    /// second column is the desired outcome, panics unless
+    /// `check(Part::Two)` passed
+    pub fn score_as_outcomes(&self) -> u32 {
+        self.rounds.iter().map(|&(op, c)| {
+            let me = self.game.choose(op, self.game.desired(c).expect("checked by Guide::check"));
+            self.game.round_score(me, op)
+        }).sum()
+    }
+}
+
+/// round log of the strategy guide, as a table or as CSV
+pub fn round_log(guide: &Guide, csv: bool) -> String {
+    let rounds = rounds(guide);
+    if csv {
+        log::to_csv(guide, &rounds)
+    } else {
+        log::to_text(guide, &rounds)
+    }
+}
+
+/// solves the strategy guide `input` of a custom game
+pub fn solve_with_game(game: &Game, input: &str, parts: &[Part]) -> Result<DayAnswers> {
+    let parse = || {
+        let guide = Guide::parse(game.clone(), input)?;
+        for &part in parts {
+            guide.check(part)?;
+        }
+        Ok(guide)
+    };
+    runner::solve_with(Day2::DAY, parts, parse, Guide::score_as_shapes, Guide::score_as_outcomes)
+}
+
+pub struct Day2;
+
+impl Puzzle for Day2 {
+    const DAY: u8 = 2;
+    type Input = Guide;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // every second column letter of rock paper scissors is read both ways
+        Guide::parse(Game::default(), input)
+    }
+
+    fn part1(guide: &Self::Input) -> u32 {
+        guide.score_as_shapes()
+    }
+
+    fn part2(guide: &Self::Input) -> u32 {
+        guide.score_as_outcomes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 15);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), 12);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let game = Game::parse(include_str!("rpsls.txt")).unwrap();
+        let (rock, spock, lizard) = (0, 1, 3);
+        assert_eq!(game.outcome(spock, rock), Outcome::Win);
+        assert_eq!(game.outcome(lizard, spock), Outcome::Win);
+        assert_eq!(game.outcome(rock, lizard), Outcome::Win);
+        let guide = Guide::parse(game, "A Y\nB X\nC Z\nD Y\nE X\n").unwrap();
+        assert_eq!(guide.score_as_shapes(), 4 + 9 + 11 + 7 + 3);
+        // several weapons reach an outcome, the highest scoring one is played
+        assert_eq!(guide.score_as_outcomes(), 4 + 5 + 11 + 7 + 4);
+    }
+
+    #[test]
+    fn letters_of_one_reading() {
+        let game = Game::parse(include_str!("rpsls.txt")).unwrap();
+        // rock and spock are only shapes, part 1 plays them
+        let guide = "A V\nB W\nE X\n";
+        let computed = solve_with_game(&game, guide, &[Part::One]).unwrap();
+        assert_eq!(computed.get(Part::One).unwrap().answer, (4 + 5 + 3).to_string());
+        assert_eq!(solve_with_game(&game, guide, &Part::BOTH).unwrap_err().to_string(),
+                   "day 2 input line 1, column 3: part 2 reads the second column as an outcome, 'V' is not one of X, Y, Z");
+        assert!(Guide::parse(game.clone(), "A U\n").is_err());
+
+        let guide = Guide::parse(game, guide).unwrap();
+        let rounds = rounds(&guide);
+        assert_eq!(rounds[0].outcomes, None);
+        assert_eq!(rounds[2].outcomes.map(|p| p.total), Some(4));
+        assert_eq!(round_log(&guide, true).lines().nth(1), Some("1,rock,V,rock,draw,4,4,,,,"));
+        let analysis = analyze(&guide);
+        assert!(analysis.puzzle[0].is_some() && analysis.puzzle[1].is_none());
+        assert_eq!(analysis.interpretations.len(), 5 * 4 * 3 + 3 * 2);
+    }
+
+    #[test]
+    fn invalid_games() {
+        let rps = "weapon rock 1 A X\nweapon paper 2 B Y\nweapon scissors 3 C Z\n";
+        let outcomes = "outcome lose 0 X\noutcome draw 3 Y\noutcome win 6 Z\n";
+        assert!(Game::parse(&format!("{}{}", rps, outcomes)).is_ok());
+        assert!(Game::parse(rps).is_err());
+        assert!(Game::parse(&format!("{}weapon well 4 D W\n{}", rps, outcomes)).is_err());
+        assert!(Game::parse(&format!("{}{}beats rock paper\n", rps, outcomes)).is_err());
+        assert_eq!(Game::parse(rps).unwrap_err().to_string(), "game definition line 3, column 1: outcome 'lose' is not defined");
+
+        let path = std::env::temp_dir().join(format!("aoc-day2-game-{}.txt", std::process::id()));
+        std::fs::write(&path, rps).unwrap();
+        let error = Game::open(&path).unwrap_err().to_string();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(error, format!("{} line 3, column 1: outcome 'lose' is not defined", path.display()));
+    }
+
+    #[test]
+    fn example_what_if() {
+        let guide = Day2::parse(EXAMPLE).unwrap();
+        let analysis = analyze(&guide);
+        assert_eq!(analysis.interpretations.len(), 12);
+        let score_of = |reading: &Reading| analysis.interpretations.iter().find(|it| it.reading == *reading).unwrap().score;
+        assert_eq!(score_of(analysis.puzzle[0].as_ref().unwrap()), 15);
+        assert_eq!(score_of(analysis.puzzle[1].as_ref().unwrap()), 12);
+        // X=scissors Y=paper Z=rock wins every round
+        assert_eq!(analysis.best().unwrap().reading, Reading::Shapes(vec![('X', 2), ('Y', 1), ('Z', 0)]));
+        assert_eq!(analysis.best().unwrap().score, 24);
+        assert_eq!((analysis.best_play, analysis.worst_play), (24, 6));
+    }
+
+    #[test]
+    fn example_round_log() {
+        let guide = Day2::parse(EXAMPLE).unwrap();
+        let rounds = rounds(&guide);
+        let totals: Vec<(u32, u32)> = rounds.iter().map(|r| (r.shapes.unwrap().total, r.outcomes.unwrap().total)).collect();
+        assert_eq!(totals, vec![(8, 4), (9, 5), (15, 12)]);
+        assert_eq!(rounds[1].shapes, Some(Play { me: 0, outcome: Outcome::Lose, score: 1, total: 9 }));
+        let csv = round_log(&guide, true);
+        assert_eq!(csv.lines().nth(1), Some("1,rock,Y,paper,win,8,8,rock,draw,4,4"));
+    }
+}
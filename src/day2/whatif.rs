@@ -0,0 +1,135 @@
+use std::fmt;
+
+use super::{Guide, Outcome};
+
+/// meaning given to the second column letters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reading {
+    /// letter to the weapon to play
+    Shapes(Vec<(char, usize)>),
+    /// letter to the desired outcome
+    Outcomes(Vec<(char, Outcome)>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Interpretation {
+    pub reading: Reading,
+    pub score: u32,
+}
+
+/// scores of a strategy guide under every reading of its second column
+#[derive(Debug)]
+pub struct Analysis {
+    names: Vec<String>,
+    /// all readings by decreasing score, ties in the order they are listed
+    pub interpretations: Vec<Interpretation>,
+    /// the reading of part 1 and part 2 of the puzzle, `None` if a letter of
+    /// the guide has no meaning in it
+    pub puzzle: [Option<Reading>; 2],
+    /// score when the best weapon is played in every round
+    pub best_play: u32,
+    /// score when the worst weapon is played in every round
+    pub worst_play: u32,
+}
+
+/// all sequences of `k` distinct values below `n`, in lexicographic order
+fn arrangements(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut acc = Vec::new();
+    for first in 0..n {
+        for mut rest in arrangements(n, k - 1).into_iter().filter(|rest| !rest.contains(&first)) {
+            rest.insert(0, first);
+            acc.push(rest);
+        }
+    }
+    acc
+}
+
+pub fn analyze(guide: &Guide) -> Analysis {
+    let game = &guide.game;
+    let n = game.weapons.len();
+    // the letters of the guide, in the order of the game
+    let letters: Vec<char> = game.second_letters().into_iter()
+        .filter(|c| guide.rounds.iter().any(|(_, x)| x == c))
+        .collect();
+    // the score only depends on how often each (opponent, letter) pair occurs
+    let mut counts = vec![vec![0u32; letters.len()]; n];
+    for &(op, c) in &guide.rounds {
+        let l = letters.iter().position(|&x| x == c).expect("collected from the guide");
+        counts[op][l] += 1;
+    }
+    let score = |me_of: &dyn Fn(usize, usize) -> usize| -> u32 {
+        let mut sum = 0;
+        for (op, row) in counts.iter().enumerate() {
+            for (l, &cnt) in row.iter().enumerate() {
+                sum += cnt * game.round_score(me_of(op, l), op);
+            }
+        }
+        sum
+    };
+
+    let mut interpretations = Vec::new();
+    for weapons in arrangements(n, letters.len()) {
+        let score = score(&|_, l| weapons[l]);
+        let reading = Reading::Shapes(letters.iter().copied().zip(weapons).collect());
+        interpretations.push(Interpretation { reading, score });
+    }
+    for outcomes in arrangements(3, letters.len()) {
+        let outcomes: Vec<Outcome> = outcomes.into_iter().map(|o| Outcome::ALL[o]).collect();
+        let score = score(&|op, l| game.choose(op, outcomes[l]));
+        let reading = Reading::Outcomes(letters.iter().copied().zip(outcomes).collect());
+        interpretations.push(Interpretation { reading, score });
+    }
+    interpretations.sort_by_key(|it| std::cmp::Reverse(it.score));
+
+    let round_scores = |op: usize| (0..n).map(move |me| game.round_score(me, op));
+    let best_play = guide.rounds.iter().map(|&(op, _)| round_scores(op).max().unwrap()).sum();
+    let worst_play = guide.rounds.iter().map(|&(op, _)| round_scores(op).min().unwrap()).sum();
+
+    let puzzle = [
+        letters.iter().map(|&c| Some((c, game.shape(c)?))).collect::<Option<_>>().map(Reading::Shapes),
+        letters.iter().map(|&c| Some((c, game.desired(c)?))).collect::<Option<_>>().map(Reading::Outcomes),
+    ];
+    let names = game.weapons.iter().map(|w| w.name.clone()).collect();
+    Analysis { names, interpretations, puzzle, best_play, worst_play }
+}
+
+impl Analysis {
+    fn describe(&self, reading: &Reading) -> String {
+        let parts: Vec<String> = match reading {
+            Reading::Shapes(m) => m.iter().map(|&(c, w)| format!("{}={}", c, self.names[w])).collect(),
+            Reading::Outcomes(m) => m.iter().map(|&(c, o)| format!("{}={}", c, o.name())).collect(),
+        };
+        parts.join(" ")
+    }
+
+    /// `None` if the guide uses more letters than there are weapons
+    pub fn best(&self) -> Option<&Interpretation> {
+        self.interpretations.first()
+    }
+
+    pub fn worst(&self) -> Option<&Interpretation> {
+        self.interpretations.last()
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for it in &self.interpretations {
+            let tag = match self.puzzle.iter().position(|r| r.as_ref() == Some(&it.reading)) {
+                Some(part) => format!("  <- part {}", part + 1),
+                None => String::new(),
+            };
+            writeln!(f, "{:>8}  {}{}", it.score, self.describe(&it.reading), tag)?;
+        }
+        writeln!(f)?;
+        if let (Some(best), Some(worst)) = (self.best(), self.worst()) {
+            writeln!(f, "best reading:  {} ({})", self.describe(&best.reading), best.score)?;
+            writeln!(f, "worst reading: {} ({})", self.describe(&worst.reading), worst.score)?;
+        }
+        writeln!(f, "best play:     {}", self.best_play)?;
+        writeln!(f, "worst play:    {}", self.worst_play)
+    }
+}
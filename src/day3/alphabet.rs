@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+/// items of the puzzle, a..z have priority 1..26 and A..Z 27..52
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Items that may be packed into a rucksack, the priority of an item is its
+/// position in the alphabet starting at 1.
+#[derive(Debug, Clone)]
+pub struct Alphabet {
+    items: Vec<char>,
+    /// index of the ascii items, the others are looked up in `other`
+    ascii: [Option<u32>; 128],
+    other: HashMap<char, usize>,
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::new(LETTERS.chars()).expect("the letters are distinct")
+    }
+}
+
+impl Alphabet {
+    /// `Err` with the first item listed twice
+    pub fn new(items: impl IntoIterator<Item = char>) -> std::result::Result<Self, char> {
+        let mut alphabet = Alphabet { items: Vec::new(), ascii: [None; 128], other: HashMap::new() };
+        for c in items {
+            if alphabet.index(c).is_some() {
+                return Err(c);
+            }
+            let idx = alphabet.items.len();
+            if c.is_ascii() {
+                alphabet.ascii[c as usize] = Some(idx as u32);
+            } else {
+                alphabet.other.insert(c, idx);
+            }
+            alphabet.items.push(c);
+        }
+        Ok(alphabet)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// position of `c`, `None` if it is not an item
+    pub fn index(&self, c: char) -> Option<usize> {
+        if c.is_ascii() {
+            self.ascii[c as usize].map(|idx| idx as usize)
+        } else {
+            self.other.get(&c).copied()
+        }
+    }
+
+    pub fn item(&self, idx: usize) -> char {
+        self.items[idx]
+    }
+
+    pub fn priority(&self, idx: usize) -> u64 {
+        idx as u64 + 1
+    }
+}
+
+/// Bitset over the items of an alphabet of any size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemSet {
+    words: Vec<u64>,
+}
+
+impl ItemSet {
+    pub fn empty(len: usize) -> Self {
+        ItemSet { words: vec![0; len.div_ceil(64)] }
+    }
+
+    /// all items of an alphabet of `len` items
+    pub fn full(len: usize) -> Self {
+        let mut set = ItemSet { words: vec![!0; len.div_ceil(64)] };
+        if !len.is_multiple_of(64) {
+            *set.words.last_mut().unwrap() = (1 << (len % 64)) - 1;
+        }
+        set
+    }
+
+    pub fn from_items(len: usize, items: &[usize]) -> Self {
+        let mut set = ItemSet::empty(len);
+        for &idx in items {
+            set.insert(idx);
+        }
+        set
+    }
+
+    pub fn insert(&mut self, idx: usize) {
+        self.words[idx / 64] |= 1 << (idx % 64);
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        self.words[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    pub fn intersect_with(&mut self, other: &ItemSet) {
+        for (w, o) in self.words.iter_mut().zip(&other.words) {
+            *w &= o;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// the items in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &w)| {
+            (0..64).filter(move |bit| w & (1 << bit) != 0).map(move |bit| i * 64 + bit)
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use super::{ItemSet, Rucksacks};
+
+/// items shared by the compartments of a rucksack or by a group
+#[derive(Debug, Clone)]
+pub struct Shared {
+    /// line of the rucksack or of the first rucksack of the group
+    pub line: usize,
+    /// number of rucksacks in a group, 1 for a single rucksack
+    pub len: usize,
+    /// `None` if the rucksack cannot be split into equal compartments or the
+    /// group is incomplete
+    pub items: Option<ItemSet>,
+}
+
+impl Shared {
+    /// anything but exactly one shared item
+    pub fn is_malformed(&self) -> bool {
+        self.items.as_ref().is_none_or(|set| set.len() != 1)
+    }
+}
+
+/// every shared item of every rucksack and group, unlike the puzzle answers
+/// malformed rucksacks and groups are listed instead of rejected
+#[derive(Debug)]
+pub struct Audit<'a> {
+    source: &'a Rucksacks,
+    pub compartments: usize,
+    pub group: usize,
+    pub rucksacks: Vec<Shared>,
+    pub groups: Vec<Shared>,
+}
+
+impl Rucksacks {
+    pub fn audit(&self, compartments: usize, group: usize) -> Audit<'_> {
+        assert!(compartments > 0 && group > 0, "compartments and groups cannot be empty");
+        let rucksacks = self.rucksacks.iter().enumerate().map(|(i, items)| {
+            Shared { line: i + 1, len: 1, items: self.compartment_set(items, compartments) }
+        }).collect();
+        let groups = self.rucksacks.chunks(group).enumerate().map(|(g, rucksacks)| {
+            let items = if rucksacks.len() == group { Some(self.group_set(rucksacks)) } else { None };
+            Shared { line: g * group + 1, len: rucksacks.len(), items }
+        }).collect();
+        Audit { source: self, compartments, group, rucksacks, groups }
+    }
+}
+
+impl Audit<'_> {
+    pub fn malformed_rucksacks(&self) -> usize {
+        self.rucksacks.iter().filter(|s| s.is_malformed()).count()
+    }
+
+    pub fn malformed_groups(&self) -> usize {
+        self.groups.iter().filter(|s| s.is_malformed()).count()
+    }
+
+    fn describe(&self, shared: &Shared, broken: &str) -> String {
+        let alphabet = &self.source.alphabet;
+        let desc = match &shared.items {
+            None => String::from(broken),
+            Some(set) if set.is_empty() => String::from("no shared item"),
+            Some(set) => set.iter()
+                            .map(|idx| format!("'{}' ({})", alphabet.item(idx), alphabet.priority(idx)))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+        };
+        if shared.is_malformed() {
+            format!("{}  [malformed]", desc)
+        } else {
+            desc
+        }
+    }
+}
+
+impl fmt::Display for Audit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for shared in &self.rucksacks {
+            let len = self.source.rucksacks[shared.line - 1].len();
+            let broken = format!("{} items cannot be split into {} equal compartments", len, self.compartments);
+            writeln!(f, "rucksack {:>6}: {}", shared.line, self.describe(shared, &broken))?;
+        }
+        for shared in &self.groups {
+            let lines = format!("{}-{}", shared.line, shared.line + shared.len - 1);
+            let broken = format!("incomplete group of {} rucksacks", shared.len);
+            writeln!(f, "group {:>9}: {}", lines, self.describe(shared, &broken))?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{} of {} rucksacks malformed", self.malformed_rucksacks(), self.rucksacks.len())?;
+        writeln!(f, "{} of {} groups of {} malformed", self.malformed_groups(), self.groups.len(), self.group)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day3;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Rucksack whose compartments share exactly `shared` and that contains `badge`.
+fn rucksack(rng: &mut Rng, shared: u8, badge: u8, own: &[u8]) -> String {
+    let mut pool: Vec<u8> = own.iter().copied().filter(|&c| c != shared).collect();
+    rng.shuffle(&mut pool);
+    let (left_pool, right_pool) = pool.split_at(pool.len() / 2);
+
+    let half = rng.range(2, 16) as usize;
+    let mut left = vec![shared];
+    let mut right = vec![shared];
+    if badge != shared {
+        // only in one compartment, otherwise it would be shared as well
+        if rng.chance(1, 2) { left.push(badge) } else { right.push(badge) }
+    }
+    while left.len() < half {
+        left.push(*rng.pick(left_pool));
+    }
+    while right.len() < half {
+        right.push(*rng.pick(right_pool));
+    }
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    left.extend(right);
+    String::from_utf8(left).unwrap()
+}
+
+impl Generate for Day3 {
+    /// `size` rucksacks, rounded up to whole groups of three
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.div_ceil(3).max(1) {
+            let badge = *rng.pick(ITEMS);
+            // the other items are split between the three elves so that
+            // the badge is the only item all of them carry
+            let mut others: Vec<u8> = ITEMS.iter().copied().filter(|&c| c != badge).collect();
+            rng.shuffle(&mut others);
+            for own in others.chunks(others.len() / 3) {
+                let shared = if rng.chance(1, 10) { badge } else { *rng.pick(own) };
+                out.push_str(&rucksack(rng, shared, badge, own));
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,244 @@
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+use crate::runner::{self, DayAnswers, Part};
+
+mod alphabet;
+mod audit;
+mod generate;
+
+pub use alphabet::{Alphabet, ItemSet};
+pub use audit::{Audit, Shared};
+
+/// rucksacks as item indices into their alphabet, one per line
+#[derive(Debug)]
+pub struct Rucksacks {
+    pub alphabet: Alphabet,
+    pub rucksacks: Vec<Vec<usize>>,
+}
+
+impl Rucksacks {
+    /// errors on the line and column of items that are not in `alphabet`
+    pub fn parse(alphabet: Alphabet, input: &str) -> Result<Self> {
+        let rucksacks = input.lines().enumerate().map(|(i, line)| {
+            line.chars().enumerate().map(|(col, c)| {
+                alphabet.index(c).ok_or_else(|| {
+                    Error::parse(Day3::DAY, i + 1, col + 1, format!("'{}' is not an item", c.escape_default()))
+                })
+            }).collect::<Result<Vec<_>>>()
+        }).collect::<Result<_>>()?;
+        Ok(Rucksacks { alphabet, rucksacks })
+    }
+
+    fn item_list(&self, set: &ItemSet) -> String {
+        set.iter().map(|idx| format!("'{}'", self.alphabet.item(idx))).collect::<Vec<_>>().join(", ")
+    }
+
+    /// the only item in `set`, an error on `line` if there is none or several
+    fn single_item(&self, set: &ItemSet, line: usize, what: &str) -> Result<usize> {
+        match set.len() {
+            1 => Ok(set.iter().next().unwrap()),
+            0 => Err(Error::parse(Day3::DAY, line, 1, format!("no item is in {}", what))),
+            _ => Err(Error::parse(Day3::DAY, line, 1, format!("items {} are all in {}", self.item_list(set), what))),
+        }
+    }
+
+    /// items in all `compartments` compartments of a rucksack, `None` if it
+    /// cannot be split into equally sized compartments
+    fn compartment_set(&self, items: &[usize], compartments: usize) -> Option<ItemSet> {
+        if items.is_empty() || !items.len().is_multiple_of(compartments) {
+            return None;
+        }
+        let n = self.alphabet.len();
+        let mut set = ItemSet::full(n);
+        for part in items.chunks(items.len() / compartments) {
+            set.intersect_with(&ItemSet::from_items(n, part));
+        }
+        Some(set)
+    }
+
+    /// items in all rucksacks of a group
+    fn group_set(&self, rucksacks: &[Vec<usize>]) -> ItemSet {
+        let n = self.alphabet.len();
+        let mut set = ItemSet::full(n);
+        for items in rucksacks {
+            set.intersect_with(&ItemSet::from_items(n, items));
+        }
+        set
+    }
+
+    /// O(n) solution: Encode the items into bitsets with one bit per item of
+    /// the alphabet. And the bitsets of the compartments, the common item is
+    /// the only bit set in the result.
+    ///
+    /// Returns the item in all `compartments` equally sized compartments of
+    /// each rucksack.
+    pub fn compartment_items(&self, compartments: usize) -> Result<Vec<usize>> {
+        assert!(compartments > 0, "a rucksack has at least one compartment");
+        self.rucksacks.iter().enumerate().map(|(i, items)| {
+            let set = self.compartment_set(items, compartments).ok_or_else(|| {
+                let msg = format!("{} items cannot be split into {} equal compartments", items.len(), compartments);
+                Error::parse(Day3::DAY, i + 1, 1, msg)
+            })?;
+            self.single_item(&set, i + 1, &format!("all {} compartments", compartments))
+        }).collect()
+    }
+
+    /// O(n) solution: For each rucksack of a group compute the bitset from
+    /// above. Then the badge is the only bit set in the and of all of them.
+    ///
+    /// Returns the badge of each group of `group` consecutive rucksacks.
+    pub fn badges(&self, group: usize) -> Result<Vec<usize>> {
+        assert!(group > 0, "a group has at least one elf");
+        self.rucksacks.chunks(group).enumerate().map(|(g, rucksacks)| {
+            let first = g * group + 1;
+            if rucksacks.len() < group {
+                let msg = format!("incomplete group of {} rucksacks, expected {}", rucksacks.len(), group);
+                return Err(Error::parse(Day3::DAY, first, 1, msg));
+            }
+            let set = self.group_set(rucksacks);
+            self.single_item(&set, first, &format!("all rucksacks of lines {} to {}", first, first + group - 1))
+        }).collect()
+    }
+
+    fn priority_sum(&self, items: &[usize]) -> u64 {
+        items.iter().map(|&idx| self.alphabet.priority(idx)).sum()
+    }
+
+    /// finds the shared items of both parts, an error if a rucksack or a
+    /// group does not have exactly one
+    fn checked(self) -> Result<Checked> {
+        let compartment_items = self.compartment_items(2)?;
+        let badges = self.badges(3)?;
+        Ok(Checked { rucksacks: self, compartment_items, badges })
+    }
+}
+
+/// rucksacks with the shared items of both parts, found once by parse
+#[derive(Debug)]
+pub struct Checked {
+    pub rucksacks: Rucksacks,
+    /// the item in both compartments of each rucksack
+    pub compartment_items: Vec<usize>,
+    /// the badge of each group of three
+    pub badges: Vec<usize>,
+}
+
+impl Checked {
+    fn challenge1(&self) -> u64 {
+        self.rucksacks.priority_sum(&self.compartment_items)
+    }
+
+    fn challenge2(&self) -> u64 {
+        self.rucksacks.priority_sum(&self.badges)
+    }
+}
+
+/// solves the rucksacks in `input` with the item priorities of `alphabet`
+pub fn solve_with_alphabet(alphabet: &Alphabet, input: &str, parts: &[Part]) -> Result<DayAnswers> {
+    runner::solve_with(Day3::DAY, parts, || Rucksacks::parse(alphabet.clone(), input)?.checked(),
+                       Checked::challenge1, Checked::challenge2)
+}
+
+pub struct Day3;
+
+impl Puzzle for Day3 {
+    const DAY: u8 = 3;
+    type Input = Checked;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Rucksacks::parse(Alphabet::default(), input)?.checked()
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input.challenge1()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        input.challenge2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input), 157);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input), 70);
+    }
+
+    fn rucksacks(lines: &[&str]) -> Rucksacks {
+        Rucksacks::parse(Alphabet::default(), &lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn any_group_and_compartment_count() {
+        let alphabet = Alphabet::default();
+        assert_eq!(rucksacks(&["abcA", "bcdB", "cdeC"]).badges(3).unwrap(), vec![alphabet.index('c').unwrap()]);
+        let rs = rucksacks(&["aXbXcX"]);
+        assert_eq!(rs.compartment_items(3).unwrap(), vec![alphabet.index('X').unwrap()]);
+        assert_eq!(rs.compartment_items(1).unwrap_err().to_string(),
+                   "day 3 input line 1, column 1: items 'a', 'b', 'c', 'X' are all in all 1 compartments");
+        assert!(rs.compartment_items(4).is_err());
+    }
+
+    #[test]
+    fn no_shared_item() {
+        let rs = rucksacks(&["ab", "cd"]);
+        assert_eq!(rs.badges(2).unwrap_err().to_string(),
+                   "day 3 input line 1, column 1: no item is in all rucksacks of lines 1 to 2");
+        assert!(rs.badges(3).is_err());
+    }
+
+    #[test]
+    fn wide_alphabet() {
+        let alphabet = Alphabet::new(('a'..='z').chain('A'..='Z').chain('0'..='9').chain("αβγ-".chars())
+                                     .chain('\u{1000}'..'\u{1040}')).unwrap();
+        assert_eq!(alphabet.len(), 130);
+        let rs = Rucksacks::parse(alphabet, "a9γb9x\n-\u{103f}\u{103f}z\n99").unwrap();
+        assert_eq!(rs.compartment_items(2).unwrap(), vec![61, 129, 61]);
+        assert_eq!(rs.priority_sum(&rs.compartment_items(2).unwrap()), 62 + 130 + 62);
+        assert_eq!(Alphabet::new("abca".chars()).unwrap_err(), 'a');
+    }
+
+    #[test]
+    fn invalid_items() {
+        let err = Day3::parse("abAB\nab1a").unwrap_err();
+        assert_eq!(err.to_string(), "day 3 input line 2, column 3: '1' is not an item");
+    }
+
+    #[test]
+    fn audit_lists_every_shared_item() {
+        let rs = rucksacks(&["abab", "abcd", "xyz", "aaaa"]);
+        let audit = rs.audit(2, 3);
+        let shared: Vec<Option<Vec<char>>> = audit.rucksacks.iter().map(|s| {
+            s.items.as_ref().map(|set| set.iter().map(|idx| rs.alphabet.item(idx)).collect())
+        }).collect();
+        assert_eq!(shared, vec![Some(vec!['a', 'b']), Some(vec![]), None, Some(vec!['a'])]);
+        assert_eq!(audit.malformed_rucksacks(), 3);
+        // abab, abcd, xyz share nothing and aaaa is an incomplete group
+        assert_eq!(audit.malformed_groups(), 2);
+        let report = audit.to_string();
+        assert!(report.contains("rucksack      1: 'a' (1), 'b' (2)  [malformed]"));
+        assert!(report.contains("rucksack      3: 3 items cannot be split into 2 equal compartments  [malformed]"));
+        assert!(report.contains("group       4-4: incomplete group of 1 rucksacks  [malformed]"));
+    }
+
+    #[test]
+    fn example_audit_is_clean() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        let audit = input.rucksacks.audit(2, 3);
+        assert_eq!((audit.malformed_rucksacks(), audit.malformed_groups()), (0, 0));
+    }
+}
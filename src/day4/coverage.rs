@@ -0,0 +1,113 @@
+use std::fmt;
+
+use super::Interval;
+
+/// How many elves clean each section, over all assignments of the file.
+///
+/// Built with a sweep line over the interval ends in O(n log n) for n
+/// assignments, the sections between the first and the last assigned
+/// section are split into maximal runs cleaned by the same number of elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// consecutive runs as (sections, number of elves), the first starts at
+    /// the lowest and the last ends at the highest assigned section
+    pub runs: Vec<(Interval, u32)>,
+}
+
+/// merges adjacent intervals of an ordered list
+fn merge_adjacent(intervals: impl Iterator<Item = Interval>) -> Vec<Interval> {
+    let mut merged: Vec<Interval> = Vec::new();
+    for iv in intervals {
+        match merged.last_mut() {
+            Some(last) if last.is_adjacent(&iv) => *last = last.hull(&iv),
+            _ => merged.push(iv),
+        }
+    }
+    merged
+}
+
+impl Coverage {
+    pub fn new<'a>(assignments: impl IntoIterator<Item = &'a Interval>) -> Self {
+        // +1 where an assignment starts and -1 after it ends, u64 as the
+        // section after u32::MAX does not fit
+        let mut events: Vec<(u64, i64)> = assignments.into_iter()
+            .flat_map(|iv| [(iv.start as u64, 1), (iv.end as u64 + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut runs: Vec<(Interval, u32)> = Vec::new();
+        let mut elves: i64 = 0;
+        let mut i = 0;
+        while i < events.len() {
+            let at = events[i].0;
+            while i < events.len() && events[i].0 == at {
+                elves += events[i].1;
+                i += 1;
+            }
+            if let Some(&(next, _)) = events.get(i) {
+                let run = Interval::new(at as u32, (next - 1) as u32);
+                match runs.last_mut() {
+                    // the count may end where it started, e.g. 1-2,3-4
+                    Some((last, n)) if *n == elves as u32 => *last = last.hull(&run),
+                    _ => runs.push((run, elves as u32)),
+                }
+            }
+        }
+        Coverage { runs }
+    }
+
+    /// sections between the first and the last assigned section
+    pub fn span(&self) -> Option<Interval> {
+        Some(self.runs.first()?.0.hull(&self.runs.last()?.0))
+    }
+
+    /// sections cleaned by exactly `k` elves
+    pub fn with(&self, k: u32) -> Vec<Interval> {
+        merge_adjacent(self.runs.iter().filter(|&&(_, n)| n == k).map(|&(iv, _)| iv))
+    }
+
+    /// sections never cleaned, inside the span
+    pub fn uncovered(&self) -> Vec<Interval> {
+        self.with(0)
+    }
+
+    /// sections cleaned by more than `k` elves
+    pub fn more_than(&self, k: u32) -> Vec<Interval> {
+        merge_adjacent(self.runs.iter().filter(|&&(_, n)| n > k).map(|&(iv, _)| iv))
+    }
+
+    /// the largest number of elves cleaning the same section and where
+    pub fn max_overlap(&self) -> (u32, Vec<Interval>) {
+        let max = self.runs.iter().map(|&(_, n)| n).max().unwrap_or(0);
+        (max, self.with(max))
+    }
+}
+
+fn list(intervals: &[Interval]) -> String {
+    if intervals.is_empty() {
+        return String::from("-");
+    }
+    intervals.iter().map(|iv| format!("{}-{}", iv.start, iv.end)).collect::<Vec<_>>().join(",")
+}
+
+/// text report of a coverage, `k` is the threshold for multiple cleaning
+pub struct Report<'a> {
+    pub coverage: &'a Coverage,
+    pub k: u32,
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(span) = self.coverage.span() else {
+            return writeln!(f, "no assignments");
+        };
+        let uncovered = self.coverage.uncovered();
+        let more = self.coverage.more_than(self.k);
+        let (max, at) = self.coverage.max_overlap();
+        let sections = |intervals: &[Interval]| intervals.iter().map(Interval::length).sum::<u64>();
+        writeln!(f, "{:<18} {}-{} ({})", "sections:", span.start, span.end, span.length())?;
+        writeln!(f, "{:<18} {} {}", "never cleaned:", sections(&uncovered), list(&uncovered))?;
+        writeln!(f, "{:<18} {} {}", format!("more than {}:", self.k), sections(&more), list(&more))?;
+        writeln!(f, "{:<18} {} elves at {}", "max overlap:", max, list(&at))
+    }
+}
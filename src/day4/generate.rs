@@ -0,0 +1,24 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day4;
+
+fn section_range(rng: &mut Rng) -> (i64, i64) {
+    let a = rng.range(1, 99);
+    (a, rng.range(a, 99))
+}
+
+impl Generate for Day4 {
+    /// `size` pairs of section assignments
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let (a, b) = section_range(rng);
+            let (c, d) = section_range(rng);
+            writeln!(out, "{}-{},{}-{}", a, b, c, d).unwrap();
+        }
+        out
+    }
+}
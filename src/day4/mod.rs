@@ -0,0 +1,254 @@
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod coverage;
+mod generate;
+
+pub use coverage::{Coverage, Report};
+
+/// inclusive range of sections, `start <= end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Interval {
+    /// interval of the sections between `a` and `b` in any order
+    pub fn new(a: u32, b: u32) -> Interval {
+        Interval { start: a.min(b), end: a.max(b) }
+    }
+
+    /// number of sections, u64 as 0-u32::MAX has one more than fits in u32
+    pub fn length(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    pub fn is_in(&self, outer: &Interval) -> bool {
+        outer.start <= self.start && self.end <= outer.end
+    }
+
+    pub fn is_cut(&self, rhs: &Interval) -> bool {
+        self.start <= rhs.start && rhs.start <= self.end
+    }
+
+    pub fn overlaps(&self, rhs: &Interval) -> bool {
+        self.is_cut(rhs) || rhs.is_cut(self)
+    }
+
+    /// touching without a common section, e.g. 2-3 and 4-5
+    pub fn is_adjacent(&self, rhs: &Interval) -> bool {
+        self.end.checked_add(1) == Some(rhs.start) || rhs.end.checked_add(1) == Some(self.start)
+    }
+
+    pub fn intersection(&self, rhs: &Interval) -> Option<Interval> {
+        if self.overlaps(rhs) {
+            Some(Interval::new(self.start.max(rhs.start), self.end.min(rhs.end)))
+        } else {
+            None
+        }
+    }
+
+    /// number of sections in both intervals
+    pub fn overlap(&self, rhs: &Interval) -> u64 {
+        self.intersection(rhs).map_or(0, |i| i.length())
+    }
+
+    /// smallest interval containing both
+    pub fn hull(&self, rhs: &Interval) -> Interval {
+        Interval::new(self.start.min(rhs.start), self.end.max(rhs.end))
+    }
+
+    /// sections in either interval, one interval if they overlap or are
+    /// adjacent and two ordered ones otherwise
+    pub fn union(&self, rhs: &Interval) -> Vec<Interval> {
+        if self.overlaps(rhs) || self.is_adjacent(rhs) {
+            vec![self.hull(rhs)]
+        } else {
+            let mut both = vec![*self, *rhs];
+            both.sort();
+            both
+        }
+    }
+
+    /// sections of `self` that are not in `rhs`, up to two intervals
+    pub fn difference(&self, rhs: &Interval) -> Vec<Interval> {
+        let Some(cut) = self.intersection(rhs) else {
+            return vec![*self];
+        };
+        let mut rest = Vec::new();
+        if self.start < cut.start {
+            rest.push(Interval::new(self.start, cut.start - 1));
+        }
+        if cut.end < self.end {
+            rest.push(Interval::new(cut.end + 1, self.end));
+        }
+        rest
+    }
+
+    /// sections between the two intervals, `None` if their union has no gap
+    pub fn gap(&self, rhs: &Interval) -> Option<Interval> {
+        match self.union(rhs)[..] {
+            [lo, hi] => Some(Interval::new(lo.end + 1, hi.start - 1)),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the assignment pair `line`, the `lineno`th line of the input.
+pub fn parseinp(line: &str, lineno: usize) -> Result<[Interval; 2]> {
+    let err = |part: &str, msg: String| Error::parse(Day4::DAY, lineno, column_of(line, part), msg);
+    let mut vres: Vec<Interval> = Vec::new();
+    for part in line.split(',') {
+        let mut it = part.split('-');
+        let leftnum = it.next().unwrap_or(part);
+        let rightnum = it.next().ok_or_else(|| err(part, format!("expected section range 'a-b', found '{}'", part)))?;
+        let ln = leftnum.parse::<u32>().map_err(|e| err(leftnum, format!("invalid section '{}': {}", leftnum, e)))?;
+        let rn = rightnum.parse::<u32>().map_err(|e| err(rightnum, format!("invalid section '{}': {}", rightnum, e)))?;
+        vres.push(Interval::new(ln, rn));
+    }
+    <[Interval; 2]>::try_from(vres).map_err(|v| err(line, format!("expected a pair of section ranges, found {}", v.len())))
+}
+
+fn challenge1(pairs: &[[Interval; 2]]) -> u32 {
+    let mut hitcnt = 0u32;
+    for xs in pairs {
+        if xs[0].is_in(&xs[1]) || xs[1].is_in(&xs[0]) {
+            // println!("hit at {:?} {:?}", xs[0], xs[1]);
+            hitcnt += 1;
+        }
+    }
+    hitcnt
+}
+
+fn challenge2(pairs: &[[Interval; 2]]) -> u32 {
+    let mut hitcnt = 0u32;
+    for xs in pairs {
+        if xs[0].overlaps(&xs[1]) {
+            // println!("hit at {:?} {:?}", xs[0], xs[1]);
+            hitcnt += 1;
+        }
+    }
+    hitcnt
+}
+
+/// number of sections assigned to both elves, per pair
+pub fn overlaps(pairs: &[[Interval; 2]]) -> Vec<u64> {
+    pairs.iter().map(|[a, b]| a.overlap(b)).collect()
+}
+
+/// sections assigned to both elves of a pair, summed over all pairs
+pub fn double_assigned(pairs: &[[Interval; 2]]) -> u64 {
+    overlaps(pairs).into_iter().sum()
+}
+
+/// index of every pair leaving unassigned sections between its two
+/// intervals, with those sections
+pub fn gaps(pairs: &[[Interval; 2]]) -> Vec<(usize, Interval)> {
+    pairs.iter().enumerate().filter_map(|(i, [a, b])| a.gap(b).map(|gap| (i, gap))).collect()
+}
+
+/// coverage of all assignments of the file, see `Coverage`
+pub fn coverage(pairs: &[[Interval; 2]]) -> Coverage {
+    Coverage::new(pairs.iter().flatten())
+}
+
+pub struct Day4;
+
+impl Puzzle for Day4 {
+    const DAY: u8 = 4;
+    /// assignment pair per line
+    type Input = Vec<[Interval; 2]>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut pairs = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            pairs.push(parseinp(line, i + 1)?);
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> u32 {
+        challenge1(pairs)
+    }
+
+    fn part2(pairs: &Self::Input) -> u32 {
+        challenge2(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), 4);
+    }
+
+    #[test]
+    fn interval_algebra() {
+        let (a, b) = (Interval::new(2, 6), Interval::new(4, 8));
+        assert_eq!(a.length(), 5);
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.union(&b), vec![Interval::new(2, 8)]);
+        assert_eq!(a.difference(&b), vec![Interval::new(2, 3)]);
+        assert_eq!(Interval::new(2, 8).difference(&Interval::new(4, 5)), vec![Interval::new(2, 3), Interval::new(6, 8)]);
+        assert!(Interval::new(4, 5).difference(&Interval::new(2, 8)).is_empty());
+        let (c, d) = (Interval::new(7, 9), Interval::new(2, 3));
+        assert!(Interval::new(2, 3).is_adjacent(&Interval::new(4, 5)));
+        assert_eq!(c.union(&d), vec![d, c]);
+        assert_eq!(c.gap(&d), Some(Interval::new(4, 6)));
+        assert_eq!(Interval::new(6, 2), a);
+        assert_eq!(Interval::new(5, 3).length(), 3);
+        assert!(Interval::new(1, 9) < Interval::new(2, 3));
+    }
+
+    #[test]
+    fn example_overlaps_and_gaps() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(overlaps(&input), vec![0, 0, 1, 5, 1, 3]);
+        assert_eq!(double_assigned(&input), 10);
+        assert_eq!(gaps(&input), vec![(0, Interval::new(5, 5))]);
+    }
+
+    #[test]
+    fn example_coverage() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        let cov = coverage(&input);
+        let counts: Vec<u32> = cov.runs.iter().flat_map(|&(iv, n)| std::iter::repeat_n(n, iv.length() as usize)).collect();
+        assert_eq!(counts, vec![4, 5, 7, 7, 8, 6, 4, 1]);
+        assert_eq!(cov.span(), Some(Interval::new(2, 9)));
+        assert!(cov.uncovered().is_empty());
+        assert_eq!(cov.more_than(5), vec![Interval::new(4, 7)]);
+        assert_eq!(cov.max_overlap(), (8, vec![Interval::new(6, 6)]));
+    }
+
+    #[test]
+    fn coverage_with_gaps() {
+        let cov = Coverage::new(&[Interval::new(1, 2), Interval::new(5, 6), Interval::new(5, 5), Interval::new(8, 9)]);
+        assert_eq!(cov.uncovered(), vec![Interval::new(3, 4), Interval::new(7, 7)]);
+        assert_eq!(cov.with(1), vec![Interval::new(1, 2), Interval::new(6, 6), Interval::new(8, 9)]);
+        assert_eq!(cov.max_overlap(), (2, vec![Interval::new(5, 5)]));
+        // adjacent assignments make a single run
+        assert_eq!(Coverage::new(&[Interval::new(1, 2), Interval::new(3, 4)]).runs, vec![(Interval::new(1, 4), 1)]);
+        assert_eq!(Coverage::new(&[Interval::new(0, u32::MAX)]).runs, vec![(Interval::new(0, u32::MAX), 1)]);
+        assert_eq!(Interval::new(0, u32::MAX).length(), 1 << 32);
+        assert_eq!(Interval::new(0, u32::MAX).overlap(&Interval::new(1, u32::MAX)), u32::MAX as u64);
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Cell, Command};
+
+/// A crane model, how crates are lifted from one stack onto another.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// moves the top `cnt` crates of stack `from` onto stack `to`
+    fn lift(&self, stacks: &mut [Vec<Cell>], cnt: usize, from: usize, to: usize);
+
+    /// stacks after carrying out all `commands`
+    fn rearrange(&self, stacks: &[Vec<Cell>], commands: &[Command]) -> Vec<Vec<Cell>> {
+        let mut stacks = stacks.to_vec();
+        for command in commands {
+            self.lift(&mut stacks, command.mov, command.from_idx(), command.to_idx());
+        }
+        stacks
+    }
+}
+
+/// moves `cnt` crates at once, keeping their order
+fn move_multiple(stacks: &mut [Vec<Cell>], cnt: usize, from_idx: usize, to_idx: usize) {
+    let at = stacks[from_idx].len() - cnt;
+    let tmp: Vec<Cell> = stacks[from_idx].drain(at..).collect();
+    stacks[to_idx].extend(tmp);
+}
+
+/// CrateMover 9000, one crate at a time
+pub struct SingleCrate;
+
+impl Crane for SingleCrate {
+    fn name(&self) -> String {
+        String::from("CrateMover 9000")
+    }
+
+    fn lift(&self, stacks: &mut [Vec<Cell>], cnt: usize, from: usize, to: usize) {
+        for _ in 0..cnt {
+            let c = stacks[from].pop().unwrap();
+            // println!("move {} from {} to {}", c.unwrap(), from, to);
+            stacks[to].push(c);
+        }
+    }
+}
+
+/// CrateMover 9001, all crates of a command at once
+pub struct WholeStack;
+
+impl Crane for WholeStack {
+    fn name(&self) -> String {
+        String::from("CrateMover 9001")
+    }
+
+    fn lift(&self, stacks: &mut [Vec<Cell>], cnt: usize, from: usize, to: usize) {
+        move_multiple(stacks, cnt, from, to);
+    }
+}
+
+/// lifts at most `k` crates per step, as many as possible in each step
+pub struct Capacity(usize);
+
+impl Capacity {
+    /// `None` for `k == 0`, such a crane would never lift anything
+    pub fn new(k: usize) -> Option<Self> {
+        (k > 0).then_some(Capacity(k))
+    }
+}
+
+impl Crane for Capacity {
+    fn name(&self) -> String {
+        format!("capacity {}", self.0)
+    }
+
+    fn lift(&self, stacks: &mut [Vec<Cell>], cnt: usize, from: usize, to: usize) {
+        let mut left = cnt;
+        while left > 0 {
+            let step = left.min(self.0);
+            move_multiple(stacks, step, from, to);
+            left -= step;
+        }
+    }
+}
+
+/// crane of a command line name: `9000` or `single`, `9001` or `stack`, and
+/// `capacity:K` for at most K crates per step
+pub fn crane(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" | "single" => Some(Box::new(SingleCrate)),
+        "9001" | "stack" => Some(Box::new(WholeStack)),
+        _ => {
+            let k = name.strip_prefix("capacity:")?.parse::<usize>().ok()?;
+            Some(Box::new(Capacity::new(k)?))
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day5;
+
+impl Generate for Day5 {
+    /// `size` rearrangement steps on 3 to 9 stacks, no step empties a stack
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let nstacks = rng.range(3, 9) as usize;
+        let mut stacks: Vec<Vec<char>> = (0..nstacks).map(|_| {
+            (0..rng.range(2, 8)).map(|_| (b'A' + rng.below(26) as u8) as char).collect()
+        }).collect();
+
+        let mut out = String::new();
+        let height = stacks.iter().map(|s| s.len()).max().unwrap();
+        for level in (0..height).rev() {
+            let row: Vec<String> = stacks.iter().map(|s| match s.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => String::from("   "),
+            }).collect();
+            writeln!(out, "{}", row.join(" ")).unwrap();
+        }
+        let base: Vec<String> = (1..=nstacks).map(|i| format!(" {} ", i)).collect();
+        writeln!(out, "{}\n", base.join(" ")).unwrap();
+
+        for _ in 0..size.max(1) {
+            // there are more crates than stacks, so some stack has two
+            let candidates: Vec<usize> = (0..nstacks).filter(|&i| stacks[i].len() > 1).collect();
+            let from = *rng.pick(&candidates);
+            let mut to = rng.below(nstacks - 1);
+            if to >= from {
+                to += 1;
+            }
+            let mov = rng.range(1, stacks[from].len() as i64 - 1) as usize;
+            let at = stacks[from].len() - mov;
+            let moved = stacks[from].split_off(at);
+            stacks[to].extend(moved);
+            writeln!(out, "move {} from {} to {}", mov, from + 1, to + 1).unwrap();
+        }
+        out
+    }
+}
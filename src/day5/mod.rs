@@ -0,0 +1,415 @@
+use std::fmt::{self, Write as _};
+
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+use crate::runner::{self, DayAnswers, Part};
+
+mod crane;
+mod generate;
+mod replay;
+mod validate;
+
+pub use crane::{crane, Capacity, Crane, SingleCrate, WholeStack};
+pub use replay::Replay;
+pub use validate::{validate, Diagnostic, Policy, Problem};
+
+/// a crate and its label, `None` for no crate
+pub type Cell = Option<String>;
+
+/// initial stacks (bottom crate first) and the rearrangement procedure
+pub type Procedure = (Vec<Vec<Cell>>, Vec<Command>);
+
+/// Reads the drawing of the stacks, the rows of crates above the numbered
+/// base row.
+///
+/// Columns are taken from the base row: a crate `[...]` belongs to the stack
+/// whose number is under it. Labels may be longer than one character and
+/// rows may be shorter than the base row, e.g. with trailing spaces removed.
+pub struct ReadDrawing {
+    stacks: Vec<Vec<Cell>>,
+}
+
+/// words of `line` with the positions of their first and last character
+fn spans(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (pos, (at, c)) in line.char_indices().chain([(line.len(), ' ')]).enumerate() {
+        match (start, c == ' ') {
+            (None, false) => start = Some((pos, at)),
+            (Some((first, from)), true) => {
+                spans.push((first, pos - 1, &line[from..at]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+/// whether `line` is the base row, only numbers separated by spaces
+fn is_base_row(line: &str) -> bool {
+    !line.trim().is_empty() && line.split_whitespace().all(|w| w.bytes().all(|b| b.is_ascii_digit()))
+}
+
+impl ReadDrawing {
+    pub fn open(lines: &[String]) -> Result<Self> {
+        let Some(base) = lines.iter().position(|l| is_base_row(l)) else {
+            return Err(Error::parse(Day5::DAY, 1, 1, "expected a drawing of the crate stacks ending in the row of stack numbers"));
+        };
+        let columns = spans(&lines[base]);
+        for (n, &(first, _, label)) in columns.iter().enumerate() {
+            if label.parse::<usize>() != Ok(n + 1) {
+                return Err(Error::parse(Day5::DAY, base + 1, first + 1,
+                                        format!("expected stack number {}, found '{}'", n + 1, label)));
+            }
+        }
+
+        let mut stacks: Vec<Vec<Cell>> = vec![Vec::new(); columns.len()];
+        for (i, lstr) in lines[..base].iter().enumerate().rev() {
+            let err = |pos: usize, msg: String| Error::parse(Day5::DAY, i + 1, pos + 1, msg);
+            let mut filled = vec![false; columns.len()];
+            for (first, last, word) in spans(lstr) {
+                let label = word.strip_prefix('[').and_then(|w| w.strip_suffix(']')).filter(|l| !l.is_empty());
+                let Some(label) = label else {
+                    return Err(err(first, format!("expected a crate '[X]', found '{}'", word)));
+                };
+                let mut under = columns.iter().enumerate().filter(|&(_, &(a, b, _))| a <= last && first <= b);
+                let col = match (under.next(), under.next()) {
+                    (Some((col, _)), None) => col,
+                    (None, _) => return Err(err(first, format!("crate '{}' is not above a stack number", word))),
+                    (Some(_), Some(_)) => return Err(err(first, format!("crate '{}' is above more than one stack number", word))),
+                };
+                if filled[col] {
+                    return Err(err(first, format!("second crate above stack {} in the same row", col + 1)));
+                }
+                filled[col] = true;
+                stacks[col].push(Some(String::from(label)));
+            }
+        }
+        Ok(Self { stacks })
+    }
+
+    pub fn stack_col(&self, col: usize) -> Vec<Cell> {
+        self.stacks[col].clone()
+    }
+
+    pub fn stacks(&self) -> Vec<Vec<Cell>> {
+        self.stacks.clone()
+    }
+}
+
+/// Drawing of `stacks` (bottom crate first) in the format of the puzzle
+/// input, including the numbered base row and trailing spaces, so that
+/// `ReadDrawing::open` reads the same stacks back. A column is as wide as
+/// its widest crate or its number.
+pub fn render(stacks: &[Vec<Cell>]) -> String {
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = stacks.iter().enumerate().map(|(i, s)| {
+        let widest = s.iter().flatten().map(|l| l.chars().count() + 2).max().unwrap_or(0);
+        widest.max(3).max((i + 1).to_string().len())
+    }).collect();
+    let mut out = String::new();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks.iter().zip(&widths).map(|(s, &w)| match s.get(row) {
+            Some(Some(c)) => format!("{:<w$}", format!("[{}]", c)),
+            _ => " ".repeat(w),
+        }).collect();
+        writeln!(out, "{}", cells.join(" ")).unwrap();
+    }
+    let labels: Vec<String> = widths.iter().enumerate().map(|(i, &w)| format!("{:^w$}", i + 1)).collect();
+    writeln!(out, "{}", labels.join(" ")).unwrap();
+    out
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub mov: usize,
+    /// stack numbers as in the input, starting at 1, `validate` checks that
+    /// they name a stack of the drawing
+    pub from: usize,
+    pub to: usize,
+    /// line of the command in the input
+    pub line: usize,
+}
+
+impl Command {
+    /// index of the stack to move from
+    pub fn from_idx(&self) -> usize {
+        self.from - 1
+    }
+
+    /// index of the stack to move to
+    pub fn to_idx(&self) -> usize {
+        self.to - 1
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.mov, self.from, self.to)
+    }
+}
+
+pub struct ReadCommands {
+    commands: Vec<Command>,
+}
+
+impl ReadCommands {
+    /// reads every line after the base row of the drawing as a command,
+    /// blank lines are skipped
+    pub fn open(lines: &[String]) -> Result<Self> {
+        let mut comms: Vec<Command> = Vec::new();
+        let start = lines.iter().position(|l| is_base_row(l)).map_or(0, |base| base + 1);
+        for (i, lstr) in lines.iter().enumerate().skip(start) {
+            if lstr.trim().is_empty() {
+                continue;
+            }
+            let err = |part: &str, msg: String| Error::parse(Day5::DAY, i + 1, column_of(lstr, part), msg);
+            let mut it = lstr.split(' ');
+            // expects `keyword` followed by a number
+            let mut field = |keyword: &str| -> Result<usize> {
+                let kw = it.next().unwrap_or(&lstr[lstr.len()..]);
+                if kw != keyword {
+                    return Err(err(kw, format!("expected '{}' in 'move N from A to B'", keyword)));
+                }
+                let num = it.next().unwrap_or(&lstr[lstr.len()..]);
+                num.parse::<usize>().map_err(|e| err(num, format!("invalid number '{}' after '{}': {}", num, keyword, e)))
+            };
+            let mov = field("move")?;
+            let from = field("from")?;
+            let to = field("to")?;
+            if let Some(rest) = it.next() {
+                return Err(err(rest, String::from("unexpected text after command")));
+            }
+            comms.push(Command { mov, from, to, line: i + 1 });
+        }
+        Ok(Self { commands: comms })
+    }
+
+    pub fn commands(&self) -> Vec<Command> {
+        self.commands.clone()
+    }
+}
+
+fn challenge1(stacks: &[Vec<Cell>], commands: &[Command]) -> String {
+    tops(&SingleCrate.rearrange(stacks, commands))
+}
+
+fn challenge2(stacks: &[Vec<Cell>], commands: &[Command]) -> String {
+    tops(&WholeStack.rearrange(stacks, commands))
+}
+
+/// labels of the top crate of each stack, a space for empty stacks
+pub fn tops(stacks: &[Vec<Cell>]) -> String {
+    stacks.iter().map(|s| s.last().and_then(|c| c.as_deref()).unwrap_or(" ")).collect()
+}
+
+/// Parses the drawing and the commands and validates the commands, see
+/// `validate`. Returns the commands to carry out and the illegal ones.
+pub fn parse_with_policy(input: &str, policy: Policy) -> Result<(Procedure, Vec<Diagnostic>)> {
+    let lines: Vec<String> = input.lines().map(String::from).collect();
+    let stacks = ReadDrawing::open(&lines)?.stacks();
+    let commands = ReadCommands::open(&lines)?.commands();
+    let (commands, diagnostics) = validate(&stacks, &commands, policy)?;
+    Ok(((stacks, commands), diagnostics))
+}
+
+/// solves the procedure in `input` with illegal commands handled according
+/// to `policy`, also returns the illegal commands
+pub fn solve_with_policy(policy: Policy, input: &str, parts: &[Part]) -> Result<(DayAnswers, Vec<Diagnostic>)> {
+    let mut diagnostics = Vec::new();
+    let parse = || parse_with_policy(input, policy).map(|(input, illegal)| {
+        diagnostics = illegal;
+        input
+    });
+    let answers = runner::solve_with(Day5::DAY, parts, parse, |(stacks, commands): &Procedure| challenge1(stacks, commands),
+                                     |(stacks, commands): &Procedure| challenge2(stacks, commands))?;
+    Ok((answers, diagnostics))
+}
+
+pub struct Day5;
+
+impl Puzzle for Day5 {
+    const DAY: u8 = 5;
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_with_policy(input, Policy::Abort).map(|(input, _)| input)
+    }
+
+    fn part1((stacks, commands): &Self::Input) -> String {
+        challenge1(stacks, commands)
+    }
+
+    fn part2((stacks, commands): &Self::Input) -> String {
+        challenge2(stacks, commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), "MCD");
+    }
+
+    #[test]
+    fn example_cranes() {
+        let (stacks, commands) = Day5::parse(EXAMPLE).unwrap();
+        let tops_of = |name: &str| tops(&crane(name).unwrap().rearrange(&stacks, &commands));
+        assert_eq!(tops_of("9000"), "CMZ");
+        assert_eq!(tops_of("capacity:1"), "CMZ");
+        assert_eq!(tops_of("9001"), "MCD");
+        assert_eq!(tops_of("capacity:3"), "MCD");
+        // move 3 from 1 to 3 lifts N D first and Z after them
+        let stacks = Capacity::new(2).unwrap().rearrange(&stacks, &commands[..2]);
+        assert_eq!(stacks[2], cells("PNDZ"));
+        assert!(crane("capacity:0").is_none());
+        assert!(Capacity::new(0).is_none());
+    }
+
+    fn cells(s: &str) -> Vec<Cell> {
+        s.chars().map(|c| Some(c.to_string())).collect()
+    }
+
+    fn round_trip(stacks: &[Vec<Cell>]) -> Vec<Vec<Cell>> {
+        let lines: Vec<String> = render(stacks).lines().map(String::from).collect();
+        ReadDrawing::open(&lines).unwrap().stacks()
+    }
+
+    #[test]
+    fn render_example() {
+        let drawing: String = EXAMPLE.lines().take(4).map(|l| format!("{}\n", l)).collect();
+        let (stacks, commands) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(render(&stacks), drawing);
+        let after = SingleCrate.rearrange(&stacks, &commands);
+        assert_eq!(render(&after), "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 \n");
+        assert_eq!(round_trip(&after), after);
+    }
+
+    #[test]
+    fn render_round_trip() {
+        for stacks in [vec![cells(""), cells(""), cells("")], vec![cells("A"), cells(""), cells("BCDEF"), cells("G")]] {
+            assert_eq!(round_trip(&stacks), stacks);
+        }
+    }
+
+    #[test]
+    fn ragged_drawings() {
+        let trimmed: String = EXAMPLE.lines().map(|l| format!("{}\n", l.trim_end())).collect();
+        assert_eq!(Day5::parse(&trimmed).unwrap().0, Day5::parse(EXAMPLE).unwrap().0);
+
+        // eleven stacks, the two-digit numbers are under their crates
+        let drawing = "[A]                                     [K]\n\
+                       [B]                                 [J] [L]\n \
+                        1   2   3   4   5   6   7   8   9  10  11\n";
+        let lines: Vec<String> = drawing.lines().map(String::from).collect();
+        let stacks = ReadDrawing::open(&lines).unwrap().stacks();
+        assert_eq!(stacks.len(), 11);
+        assert_eq!(tops(&stacks), "A        JK");
+        assert_eq!(stacks[10], cells("LK"));
+        assert_eq!(round_trip(&stacks), stacks);
+
+        // labels longer than one character
+        let drawing = "     [XY]\n[Z]  [W]  [ABC]\n 1    2    3\n";
+        let lines: Vec<String> = drawing.lines().map(String::from).collect();
+        let stacks = ReadDrawing::open(&lines).unwrap().stacks();
+        assert_eq!(tops(&stacks), "ZXYABC");
+        assert_eq!(render(&stacks), "    [XY]      \n[Z] [W]  [ABC]\n 1   2     3  \n");
+        assert_eq!(round_trip(&stacks), stacks);
+    }
+
+    #[test]
+    fn drawing_errors() {
+        let error = |drawing: &str| {
+            let lines: Vec<String> = drawing.lines().map(String::from).collect();
+            ReadDrawing::open(&lines).err().map(|e| e.to_string())
+        };
+        assert_eq!(error("[A]\n"), Some(String::from(
+            "day 5 input line 1, column 1: expected a drawing of the crate stacks ending in the row of stack numbers")));
+        assert_eq!(error("[A] [B\n 1   2\n"), Some(String::from(
+            "day 5 input line 1, column 5: expected a crate '[X]', found '[B'")));
+        assert_eq!(error("        [C]\n 1   2\n"), Some(String::from(
+            "day 5 input line 1, column 9: crate '[C]' is not above a stack number")));
+        assert_eq!(error(" 1   3\n"), Some(String::from(
+            "day 5 input line 1, column 6: expected stack number 2, found '3'")));
+    }
+
+    #[test]
+    fn replay_with_undo() {
+        let (stacks, commands) = Day5::parse(EXAMPLE).unwrap();
+        for crane in [&SingleCrate as &dyn Crane, &WholeStack, &Capacity::new(2).unwrap()] {
+            let mut replay = Replay::new(crane, &stacks, &commands);
+            let mut snapshots = vec![replay.snapshot()];
+            while let Some(command) = replay.step() {
+                assert!(replay.stacks()[command.to_idx()].len() >= command.mov);
+                snapshots.push(replay.snapshot());
+            }
+            assert_eq!(replay.position(), 4);
+            assert_eq!(snapshots[4], crane.rearrange(&stacks, &commands));
+            replay.seek(1);
+            assert_eq!(replay.stacks(), &snapshots[1][..]);
+            assert_eq!(replay.undo().map(|c| c.to_string()), Some(String::from("move 1 from 2 to 1")));
+            assert!(replay.undo().is_none());
+            assert_eq!(replay.stacks(), &stacks[..]);
+            replay.seek(10);
+            assert_eq!(replay.render(), render(&snapshots[4]));
+        }
+    }
+
+    const ILLEGAL: &str = "[A]    \n[B] [C]\n 1   2 \n\nmove 3 from 1 to 2\nmove 1 from 3 to 1\nmove 1 from 2 to 1\n";
+
+    #[test]
+    fn illegal_commands_abort() {
+        assert_eq!(Day5::parse(ILLEGAL).unwrap_err().to_string(),
+                   "day 5 input line 5, column 1: 'move 3 from 1 to 2': stack 1 holds only 2 crates (stack sizes 2 1)");
+    }
+
+    #[test]
+    fn illegal_commands_skip_or_clamp() {
+        let ((stacks, commands), diagnostics) = parse_with_policy(ILLEGAL, Policy::Skip).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(diagnostics.iter().map(|d| d.problem.clone()).collect::<Vec<_>>(),
+                   vec![Problem::TooFew { have: 2 }, Problem::NoStack(3)]);
+        assert_eq!(tops(&SingleCrate.rearrange(&stacks, &commands)), "C ");
+        assert_eq!(diagnostics[1].to_string(), "'move 1 from 3 to 1': there is no stack 3, only 2 (stack sizes 2 1)");
+
+        let ((stacks, commands), diagnostics) = parse_with_policy(ILLEGAL, Policy::Clamp).unwrap();
+        assert_eq!(commands.iter().map(|c| c.mov).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(tops(&SingleCrate.rearrange(&stacks, &commands)), "BA");
+    }
+
+    #[test]
+    fn stack_zero_follows_policy() {
+        let input = ILLEGAL.replace("from 3 to 1", "from 0 to 2");
+        assert_eq!(Day5::parse(&input.replace("move 3", "move 2")).unwrap_err().to_string(),
+                   "day 5 input line 6, column 1: 'move 1 from 0 to 2': there is no stack 0, only 2 (stack sizes 0 3)");
+        for policy in [Policy::Skip, Policy::Clamp] {
+            let ((_, commands), diagnostics) = parse_with_policy(&input, policy).unwrap();
+            assert_eq!(commands.last().map(|c| c.to_string()), Some(String::from("move 1 from 2 to 1")));
+            assert_eq!(diagnostics.last().map(|d| d.problem.clone()), Some(Problem::NoStack(0)));
+        }
+    }
+
+    #[test]
+    fn only_commands_after_the_drawing() {
+        let input = ILLEGAL.replace("move 1 from 3 to 1", "mov 1 from 2 to 1");
+        assert_eq!(parse_with_policy(&input, Policy::Skip).err().map(|e| e.to_string()), Some(String::from(
+            "day 5 input line 6, column 1: expected 'move' in 'move N from A to B'")));
+        assert!(Day5::parse(&ILLEGAL.replace("move 3", "move 2").replace("from 3", "from 2").replace("\n\n", "\n\n\n")).is_ok());
+    }
+}
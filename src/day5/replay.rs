@@ -0,0 +1,84 @@
+use super::{render, Cell, Command, Crane};
+
+/// crates taken off a stack by a command, enough to undo it
+struct Lifted {
+    from: usize,
+    to: usize,
+    crates: Vec<Cell>,
+}
+
+/// Steps through the rearrangement procedure one command at a time, forward
+/// and backward.
+///
+/// Undo keeps only the crates each command lifted, not copies of all stacks.
+pub struct Replay<'a> {
+    crane: &'a dyn Crane,
+    commands: &'a [Command],
+    stacks: Vec<Vec<Cell>>,
+    history: Vec<Lifted>,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(crane: &'a dyn Crane, stacks: &[Vec<Cell>], commands: &'a [Command]) -> Self {
+        Replay { crane, commands, stacks: stacks.to_vec(), history: Vec::new() }
+    }
+
+    /// number of commands carried out so far
+    pub fn position(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// the stacks after `position` commands
+    pub fn stacks(&self) -> &[Vec<Cell>] {
+        &self.stacks
+    }
+
+    /// a copy of the current stacks
+    pub fn snapshot(&self) -> Vec<Vec<Cell>> {
+        self.stacks.clone()
+    }
+
+    /// the current stacks in the format of the puzzle input
+    pub fn render(&self) -> String {
+        render(&self.stacks)
+    }
+
+    /// carries out the next command, `None` at the end
+    pub fn step(&mut self) -> Option<&'a Command> {
+        let command = self.commands.get(self.position())?;
+        let (from, to) = (command.from_idx(), command.to_idx());
+        let crates = self.stacks[from][self.stacks[from].len() - command.mov..].to_vec();
+        self.crane.lift(&mut self.stacks, command.mov, from, to);
+        self.history.push(Lifted { from, to, crates });
+        Some(command)
+    }
+
+    /// takes back the last command, `None` at the start
+    pub fn undo(&mut self) -> Option<&'a Command> {
+        let Lifted { from, to, crates } = self.history.pop()?;
+        let to_len = self.stacks[to].len();
+        self.stacks[to].truncate(to_len - crates.len());
+        self.stacks[from].extend(crates);
+        Some(&self.commands[self.position()])
+    }
+
+    /// steps forward or backward until `n` commands are carried out, at most
+    /// all of them
+    pub fn seek(&mut self, n: usize) {
+        let n = n.min(self.len());
+        while self.position() < n {
+            self.step();
+        }
+        while self.position() > n {
+            self.undo();
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+use super::{Cell, Command, Day5};
+
+/// what to do with a command that cannot be carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// the input is rejected at the first illegal command
+    #[default]
+    Abort,
+    /// illegal commands are left out
+    Skip,
+    /// as many crates as the stack holds are moved, commands naming an
+    /// unknown stack are left out
+    Clamp,
+}
+
+impl Policy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "abort" => Some(Policy::Abort),
+            "skip" => Some(Policy::Skip),
+            "clamp" => Some(Policy::Clamp),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// number of a stack that is not in the drawing, 0 or more than there
+    /// are stacks
+    NoStack(usize),
+    /// the stack to move from holds fewer crates than the command moves
+    TooFew { have: usize },
+}
+
+/// an illegal command and the stack sizes just before it
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub command: Command,
+    pub sizes: Vec<usize>,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sizes: Vec<String> = self.sizes.iter().map(|n| n.to_string()).collect();
+        match self.problem {
+            Problem::NoStack(n) => write!(f, "'{}': there is no stack {}, only {}", self.command, n, self.sizes.len())?,
+            Problem::TooFew { have } => {
+                write!(f, "'{}': stack {} holds only {} crates", self.command, self.command.from, have)?
+            }
+        }
+        write!(f, " (stack sizes {})", sizes.join(" "))
+    }
+}
+
+/// Checks that `commands` can be carried out on `stacks` by any crane,
+/// stack sizes do not depend on the crane model. Returns the commands to
+/// carry out according to `policy` and what was wrong with the others.
+pub fn validate(stacks: &[Vec<Cell>], commands: &[Command], policy: Policy)
+                -> Result<(Vec<Command>, Vec<Diagnostic>)> {
+    let mut sizes: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+    let mut valid = Vec::with_capacity(commands.len());
+    let mut diagnostics = Vec::new();
+    for command in commands {
+        let problem = if let Some(n) = [command.from, command.to].into_iter().find(|&n| n == 0 || n > sizes.len()) {
+            Some(Problem::NoStack(n))
+        } else if sizes[command.from_idx()] < command.mov {
+            Some(Problem::TooFew { have: sizes[command.from_idx()] })
+        } else {
+            None
+        };
+        let mut command = command.clone();
+        if let Some(problem) = problem {
+            let diagnostic = Diagnostic { command: command.clone(), sizes: sizes.clone(), problem };
+            match (policy, &diagnostic.problem) {
+                (Policy::Abort, _) => {
+                    return Err(Error::parse(Day5::DAY, command.line, 1, diagnostic.to_string()));
+                }
+                (Policy::Clamp, &Problem::TooFew { have }) if have > 0 => {
+                    command.mov = have;
+                    diagnostics.push(diagnostic);
+                }
+                _ => {
+                    diagnostics.push(diagnostic);
+                    continue;
+                }
+            }
+        }
+        sizes[command.from_idx()] -= command.mov;
+        sizes[command.to_idx()] += command.mov;
+        valid.push(command);
+    }
+    Ok((valid, diagnostics))
+}
@@ -0,0 +1,31 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day6;
+
+/// `len` distinct random letters
+fn distinct(rng: &mut Rng, len: usize) -> Vec<u8> {
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    letters.truncate(len);
+    letters
+}
+
+impl Generate for Day6 {
+    /// datastream of `size` characters (at least 30)
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let len = size.max(30);
+        // noise of three letters never contains a marker on its own
+        let noise: Vec<u8> = distinct(rng, 3);
+        let mut ds: Vec<u8> = (0..len).map(|_| *rng.pick(&noise)).collect();
+
+        let packet = rng.below(len / 2 - 4);
+        ds[packet..packet + 4].copy_from_slice(&distinct(rng, 4));
+        let message = len / 2 + rng.below(len - len / 2 - 14 + 1);
+        ds[message..message + 14].copy_from_slice(&distinct(rng, 14));
+
+        let mut out = String::from_utf8(ds).unwrap();
+        out.push('\n');
+        out
+    }
+}
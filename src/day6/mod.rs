@@ -0,0 +1,95 @@
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+fn sliding_windows(inp: &str, len: usize) -> impl Iterator<Item = (usize, &str)> {
+    inp.char_indices().flat_map(move |(from, _)| {
+        let start = &inp[from ..];
+        start.char_indices().nth(len - 1).map(|(to, _)| {
+            (from, &inp[from .. from + to + 1])
+        }) 
+    })
+}
+
+fn is_marking(slice: &[u8]) -> bool {
+    for (i, x) in slice.iter().enumerate() {
+        for (j, y) in slice.iter().enumerate() {
+            if i != j && x == y {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Number of characters processed until the first window of `len` distinct
+/// characters is complete.
+fn find_marker(datastream: &str, len: usize) -> Option<usize> {
+    for (start, window) in sliding_windows(datastream, len) {
+        if is_marking(window.as_bytes()) {
+            // println!("mark {} starts at {}", window, start);
+            return Some(start + len);
+        }
+    }
+    None
+}
+
+/// number of characters processed until each marker is complete
+#[derive(Debug)]
+pub struct Markers {
+    pub packet: usize,
+    pub message: usize,
+}
+
+pub struct Day6;
+
+impl Puzzle for Day6 {
+    const DAY: u8 = 6;
+    type Input = Markers;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let datastream = input.lines().next().unwrap_or_default();
+        let marker = |len: usize, name: &str| find_marker(datastream, len).ok_or_else(|| {
+            Error::parse(Day6::DAY, 1, 1, format!("no {} marker, {} different characters in a row", name, len))
+        });
+        Ok(Markers { packet: marker(4, "start-of-packet")?, message: marker(14, "start-of-message")? })
+    }
+
+    fn part1(markers: &Self::Input) -> usize {
+        markers.packet
+    }
+
+    fn part2(markers: &Self::Input) -> usize {
+        markers.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), 7);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), 19);
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(Day6::parse("").unwrap_err().to_string(),
+                   "day 6 input line 1, column 1: no start-of-packet marker, 4 different characters in a row");
+        assert_eq!(Day6::parse("abcdabcd").unwrap_err().to_string(),
+                   "day 6 input line 1, column 1: no start-of-message marker, 14 different characters in a row");
+    }
+}
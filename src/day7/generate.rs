@@ -0,0 +1,59 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day7;
+
+struct Dir {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(String, usize)>,
+}
+
+fn write_listing(out: &mut String, tree: &[Dir], dir: usize, rng: &mut Rng) {
+    writeln!(out, "$ ls").unwrap();
+    let mut entries: Vec<String> = tree[dir].dirs.iter().map(|&d| format!("dir {}", tree[d].name)).collect();
+    entries.extend(tree[dir].files.iter().map(|(name, size)| format!("{} {}", size, name)));
+    rng.shuffle(&mut entries);
+    for e in entries {
+        writeln!(out, "{}", e).unwrap();
+    }
+    for &d in &tree[dir].dirs {
+        writeln!(out, "$ cd {}", tree[d].name).unwrap();
+        write_listing(out, tree, d, rng);
+        writeln!(out, "$ cd ..").unwrap();
+    }
+}
+
+impl Generate for Day7 {
+    /// terminal log of exploring a file system with `size` files in about
+    /// `size / 4` directories, using 45 to 65 of the 70 MB disk
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let nfiles = size.max(1);
+        let mut tree = vec![Dir { name: String::from("/"), dirs: Vec::new(), files: Vec::new() }];
+        for i in 1..=nfiles / 4 {
+            let parent = rng.below(tree.len());
+            // letters followed by a number that is unique among files and
+            // directories keep all names distinct
+            tree.push(Dir { name: format!("{}{}", rng.word(1, 6), nfiles + i), dirs: Vec::new(), files: Vec::new() });
+            tree[parent].dirs.push(i);
+        }
+
+        let weights: Vec<usize> = (0..nfiles).map(|_| rng.range(1, 1000) as usize).collect();
+        let total: usize = weights.iter().sum();
+        let used = rng.range(45_000_000, 65_000_000) as usize;
+        for (i, w) in weights.into_iter().enumerate() {
+            let mut name = format!("{}{}", rng.word(1, 6), i);
+            if rng.chance(1, 2) {
+                name = format!("{}.{}", name, rng.word(1, 3));
+            }
+            let dir = rng.below(tree.len());
+            tree[dir].files.push((name, (w * used / total).max(1)));
+        }
+
+        let mut out = String::from("$ cd /\n");
+        write_listing(&mut out, &tree, 0, rng);
+        out
+    }
+}
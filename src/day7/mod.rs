@@ -1,12 +1,14 @@
 use std::borrow::{Borrow, BorrowMut};
-use std::{env, usize};
-use std::fs::File;
-use std::io::prelude::*;
-use std::cell::{RefCell, Cell};
+use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
 #[derive(Debug)]
-struct FileNode {
+pub struct FileNode {
     parent: Option<Rc<RefCell<FileNode>>>,
     children: Option<Vec<Rc<RefCell<FileNode>>>>,
     name: String,
@@ -17,15 +19,15 @@ struct FileNode {
 
 impl FileNode {
     fn new_dir(parent: &Rc<RefCell<FileNode>>, name: &str) -> Self {
-        let s = Self { 
+        
+        Self { 
             parent: Some(parent.clone()), 
             children: Some(Vec::new()), 
             name: String::from(name), 
             size: 0, 
             is_dir: true, 
             is_root: false 
-        };
-        s
+        }
     }
     
     fn new_file(parent: &Rc<RefCell<FileNode>>, name: &str, size: usize) -> Self {
@@ -33,7 +35,7 @@ impl FileNode {
             parent: Some(parent.clone()), 
             children: None, 
             name: String::from(name), 
-            size: size, 
+            size, 
             is_dir: false, 
             is_root: false 
         };
@@ -52,19 +54,19 @@ impl FileNode {
         }
     }
 
-    fn get_parent(&self) -> &RefCell<FileNode> {
+    pub fn get_parent(&self) -> &RefCell<FileNode> {
         self.parent.as_ref().unwrap().borrow()
     }
 
-    fn get_children(&self) -> Option<&Vec<Rc<RefCell<FileNode>>>> {
+    pub fn get_children(&self) -> Option<&Vec<Rc<RefCell<FileNode>>>> {
         self.borrow().children.as_ref()
     }
 
-    fn get_children_mut(&mut self) -> Option<&mut Vec<Rc<RefCell<FileNode>>>> {
+    pub fn get_children_mut(&mut self) -> Option<&mut Vec<Rc<RefCell<FileNode>>>> {
         self.borrow_mut().children.as_mut()
     }
 
-    fn abs_pathname(&self) -> String {
+    pub fn abs_pathname(&self) -> String {
         if self.is_root {
             return String::from("/");
         }
@@ -76,7 +78,7 @@ impl FileNode {
         }
     }
     
-    fn get_size(&self) -> usize {
+    pub fn get_size(&self) -> usize {
         self.size
     }
 
@@ -89,7 +91,7 @@ impl FileNode {
     }
 }
 
-fn mk_root() -> Rc<RefCell<FileNode>> {
+pub fn mk_root() -> Rc<RefCell<FileNode>> {
     Rc::new(RefCell::new(FileNode::new_root()))
 }
 
@@ -119,13 +121,13 @@ fn get_size(node: &Rc<RefCell<FileNode>>) -> usize {
     node.as_ref().borrow().borrow().size
 }
 
-struct FileSystem {
+pub struct FileSystem {
     root: Rc<RefCell<FileNode>>,
     cwd: Rc<RefCell<FileNode>>
 }
 
 impl FileSystem {
-    fn new(root: Rc<RefCell<FileNode>>) -> Self {
+    pub fn new(root: Rc<RefCell<FileNode>>) -> Self {
         Self { root: root.clone(), cwd: root }
     }
 
@@ -151,55 +153,57 @@ impl FileSystem {
 }
 
 struct InputReader<'a> {
-    file: &'a File,
+    input: &'a str,
     fs: &'a mut FileSystem,
 }
 
 impl<'a> InputReader<'a> {
-    fn new(file: &'a File, fs: &'a mut FileSystem) -> Self {
-        Self { file: file, fs: fs }
+    fn new(input: &'a str, fs: &'a mut FileSystem) -> Self {
+        Self { input, fs }
     }
 
-    fn parse(&mut self) -> std::io::Result<()> {
-        let reader = std::io::BufReader::new(self.file);
-        let mut lineit = reader.lines().peekable();
-        while let Some(Ok(line)) = lineit.next() {
+    fn parse(&mut self) -> Result<()> {
+        let mut lineit = self.input.lines().enumerate().peekable();
+        while let Some((i, line)) = lineit.next() {
+            let err = |part: &str, msg: String| Error::parse(Day7::DAY, i + 1, column_of(line, part), msg);
             if line.starts_with("$ cd") {
                 if let Some(dirname) = line.split(' ').nth(2) {
-                    self.fs.chdir(dirname).unwrap();
+                    self.fs.chdir(dirname).ok_or_else(|| err(dirname, format!("no directory '{}' in the current directory", dirname)))?;
                 }
             } else if line.starts_with("$ ls") {
-                while let Some(Ok(listline)) = lineit.next() {
+                while let Some(&(j, listline)) = lineit.peek() {
+                    if listline.starts_with("$ ") {
+                        break;
+                    }
+                    let err = |part: &str, msg: String| Error::parse(Day7::DAY, j + 1, column_of(listline, part), msg);
                     if listline.starts_with("dir ") {
                         if let Some(dirname) = listline.split(' ').nth(1) {
                             mk_dir(&mut self.fs.cwd.clone(), dirname);
                         }
                     } else {
                         let mut parts = listline.split(' ');
-                        if let Some(fsize) = parts.nth(0) {
-                            if let Some(fname) = parts.nth(0) {
-                                let sz = fsize.parse::<usize>().unwrap();
+                        if let Some(fsize) = parts.next() {
+                            if let Some(fname) = parts.next() {
+                                let sz = fsize.parse::<usize>().map_err(|e| err(fsize, format!("invalid file size '{}': {}", fsize, e)))?;
                                 mk_file(&mut self.fs.cwd.clone(), fname, sz);
                             }
                         }
                     }
-                    if let Some(Ok(p)) = lineit.peek() {
-                        if p.starts_with("$ ") {
-                            break;
-                        }
-                    }
+                    lineit.next();
                 }
+            } else {
+                return Err(err(line, String::from("expected a command starting with '$ '")));
             }
         }
         Ok(())
     }
 }
 
-fn traverse(root: &Rc<RefCell<FileNode>>) {
+pub fn traverse(root: &Rc<RefCell<FileNode>>) {
     if let Some(cs) = &root.as_ref().borrow().children {
         println!("# childrens of {} is {}", root.clone().as_ref().borrow().name, cs.len());
         for cnode in cs {
-            traverse(&cnode);
+            traverse(cnode);
         }
     }
     
@@ -210,7 +214,7 @@ fn traverse(root: &Rc<RefCell<FileNode>>) {
 }
 
 fn solve1(root: &Rc<RefCell<FileNode>>) -> usize {
-    if root.clone().as_ref().borrow().is_dir == false {
+    if !root.clone().as_ref().borrow().is_dir {
         return 0;
     }
 
@@ -222,7 +226,7 @@ fn solve1(root: &Rc<RefCell<FileNode>>) -> usize {
             }
             let s = get_size(cnode);
             if s < 100000 {
-                println!("{} as {}", get_abs_pathname(cnode), s);
+                // println!("{} as {}", get_abs_pathname(cnode), s);
                 coll += s;
             }
             coll += solve1(cnode);
@@ -231,7 +235,7 @@ fn solve1(root: &Rc<RefCell<FileNode>>) -> usize {
     coll
 }
 
-fn print_childrens(root: &Rc<RefCell<FileNode>>) {
+pub fn print_childrens(root: &Rc<RefCell<FileNode>>) {
     println!("{} | size {}", get_abs_pathname(root), get_size(root));
     if let Some(cs) = &root.as_ref().borrow().children {
         for cnode in cs {
@@ -251,19 +255,19 @@ fn collect(root: &Rc<RefCell<FileNode>>, candidates: &mut Vec<Rc<RefCell<FileNod
         }
     }    
     if get_size(root) >= required {
-        println!("add {} with size {} to candidates", get_abs_pathname(root), get_size(root));
+        // println!("add {} with size {} to candidates", get_abs_pathname(root), get_size(root));
         candidates.push(root.clone());
     } 
 }
 
-fn solve2(root: &Rc<RefCell<FileNode>>) {
+fn solve2(root: &Rc<RefCell<FileNode>>) -> usize {
     let mut frontier: Vec<Rc<RefCell<FileNode>>> = Vec::new();
-    let used = get_size(&root);
+    let used = get_size(root);
     let unused = 70000000 - used;
     let minimum = 30000000;
     let required = minimum - unused;
-    println!("unused   {}", unused);
-    println!("required {}", required);
+    // println!("unused   {}", unused);
+    // println!("required {}", required);
     
     collect(root, &mut frontier, required);
 
@@ -271,23 +275,47 @@ fn solve2(root: &Rc<RefCell<FileNode>>) {
     for node in frontier {
         curr = std::cmp::min(curr, get_size(&node));
     }
-    println!("best candidate has size {}", curr);
+    curr
 }
 
-fn main() -> std::io::Result<()> {
-    let argv: Vec<String> = env::args().collect();
-    if argv.len() < 2 {
-        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Expected filename as argument"));
+pub struct Day7;
+
+impl Puzzle for Day7 {
+    const DAY: u8 = 7;
+    type Input = FileSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut fs = FileSystem::new(mk_root());
+        InputReader::new(input, &mut fs).parse()?;
+        Ok(fs)
+    }
+
+    fn part1(fs: &Self::Input) -> usize {
+        solve1(&fs.root)
     }
-    let file = File::open(&argv[1])?;
-    let mut fs = FileSystem::new(mk_root());
 
-    InputReader::new(&file, &mut fs).parse()?;
-    // println!("-----------------");
-    // let s = solve1(&fs.root);
-    // println!("size {}", s);
+    fn part2(fs: &Self::Input) -> usize {
+        solve2(&fs.root)
+    }
+}
 
-    solve2(&fs.root);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(())
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 95437);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 24933642);
+    }
 }
@@ -0,0 +1,17 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day8;
+
+impl Generate for Day8 {
+    /// `size` x `size` grid of tree heights
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        let mut out = String::with_capacity(side * (side + 1));
+        for _ in 0..side {
+            out.extend((0..side).map(|_| (b'0' + rng.below(10) as u8) as char));
+            out.push('\n');
+        }
+        out
+    }
+}
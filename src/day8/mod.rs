@@ -1,43 +1,55 @@
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-
-struct Grid {
-    data: Vec<u8>,
-    rows: usize,
-    cols: usize,
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+pub struct Grid {
+    pub data: Vec<u8>,
+    pub rows: usize,
+    pub cols: usize,
 }
 
 impl Grid {
-    fn read_file(file: File) -> std::io::Result<Grid> {
-        let reader = std::io::BufReader::new(file);
+    pub fn parse(input: &str) -> Result<Grid> {
         let mut data: Vec<u8> = Vec::new();
         let mut rows = 0;
         let mut cols = 0;
-        for line in reader.lines() {
-            let lstr = line?;
-            if rows == 0 {
-                rows = lstr.as_bytes().len();
+        for (i, lstr) in input.lines().enumerate() {
+            let err = |column, msg: String| Error::parse(Day8::DAY, i + 1, column, msg);
+            if lstr.is_empty() {
+                return Err(err(1, String::from("empty row of trees")));
+            }
+            if i == 0 {
+                rows = lstr.len();
             }
-            data.extend(lstr.as_bytes().iter().map(|b| b - '0' as u8));
+            if let Some((j, c)) = lstr.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(err(j + 1, format!("tree height must be a digit, found '{}'", c)));
+            }
+            if lstr.len() != rows {
+                return Err(err(1, format!("expected {} trees per row, found {}", rows, lstr.len())));
+            }
+            data.extend(lstr.as_bytes().iter().map(|b| b - b'0'));
             cols += 1;
         }
-        Ok(Self { data: data, rows: rows, cols: cols })
+        if cols == 0 {
+            return Err(Error::parse(Day8::DAY, 1, 1, "expected a grid of tree heights"));
+        }
+        Ok(Self { data, rows, cols })
     }
 
-    fn empty(rows: usize, cols: usize) -> Self {
+    pub fn empty(rows: usize, cols: usize) -> Self {
         let data = vec![0u8; rows * cols];
-        Self { data: data, rows: rows, cols: cols}
+        Self { data, rows, cols}
     }
 
-    fn read(&self, i: usize, j: usize) -> Option<u8> {
+    pub fn read(&self, i: usize, j: usize) -> Option<u8> {
         if i > self.rows || j > self.cols {
             return None;
         }
         Some(self.data[i * self.cols + j])
     }
 
-    fn set(&mut self, i: usize, j: usize, val: u8) -> Option<()> {
+    pub fn set(&mut self, i: usize, j: usize, val: u8) -> Option<()> {
         if i > self.rows || j > self.cols {
             return None;
         }
@@ -47,7 +59,7 @@ impl Grid {
 }
 
 
-fn compute_vmap(grid: &Grid) -> Grid {
+pub fn compute_vmap(grid: &Grid) -> Grid {
     let mut vmap = Grid::empty(grid.rows, grid.cols);
     
     let mut rvmap = Grid::empty(grid.rows, grid.cols);
@@ -109,7 +121,7 @@ fn compute_vmap(grid: &Grid) -> Grid {
     vmap 
 }
 
-fn compute_max_scenic_score(grid: &Grid) -> usize {
+pub fn compute_max_scenic_score(grid: &Grid) -> usize {
     let mut max_score = 0;
     for i in 0..grid.rows {
         for j in 0..grid.cols {
@@ -149,16 +161,52 @@ fn compute_max_scenic_score(grid: &Grid) -> usize {
     max_score
 }
 
-fn main() -> std::io::Result<()> {
-    let argv: Vec<String> = env::args().collect();
-    if argv.len() < 2 {
-        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Expected filename as argument"));
-    }
-    let file = File::open(&argv[1])?;
-    let grid = Grid::read_file(file)?;
-    let vmap = compute_vmap(&grid);
-    println!("sum visible: {}", vmap.data.iter().map(|x| *x as usize).sum::<usize>());
-    let score = compute_max_scenic_score(&grid);
-    println!("max scenic score is {}", score);
-    Ok(())
+pub struct Day8;
+
+impl Puzzle for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input)
+    }
+
+    /// number of trees visible from outside the grid
+    fn part1(grid: &Self::Input) -> usize {
+        let vmap = compute_vmap(grid);
+        vmap.data.iter().map(|x| *x as usize).sum::<usize>()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        compute_max_scenic_score(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), 21);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), 8);
+    }
+
+    #[test]
+    fn blank_rows() {
+        assert_eq!(Day8::parse("").err().unwrap().to_string(),
+                   "day 8 input line 1, column 1: expected a grid of tree heights");
+        assert_eq!(Day8::parse("\n123").err().unwrap().to_string(), "day 8 input line 1, column 1: empty row of trees");
+        assert_eq!(Day8::parse("123\n\n456").err().unwrap().to_string(), "day 8 input line 2, column 1: empty row of trees");
+    }
 }
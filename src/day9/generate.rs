@@ -0,0 +1,17 @@
+use std::fmt::Write as _;
+
+use crate::generate::Generate;
+use crate::rng::Rng;
+
+use super::Day9;
+
+impl Generate for Day9 {
+    /// `size` head motions of 1 to 20 steps
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            writeln!(out, "{} {}", rng.pick(&['R', 'U', 'L', 'D']), rng.range(1, 20)).unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use crate::error::{column_of, Error, Result};
+use crate::puzzle::Puzzle;
+
+mod generate;
+
+pub type Pos = (i32, i32);
+
+#[derive(Debug)]
+pub enum Step {
+    Up(i32),
+    Down(i32),
+    Right(i32),
+    Left(i32)
+}
+
+fn update_headpos(head: Pos, step: Step) -> Pos {
+    let (x, y) = head;
+    match step {
+        Step::Up(dx) => (x + dx, y),
+        Step::Down(dx) => (x - dx, y),
+        Step::Right(dy) => (x, y + dy),
+        Step::Left(dy) => (x, y - dy),
+    }
+}
+
+fn update_tailpos(tail: Pos, head: Pos) -> Pos {
+    let (hx, hy) = head;
+    let (tx, ty) = tail;
+    let (dx, dy) = (hx - tx, hy - ty);
+    // directional offset
+    let (sx, sy) = (dx.signum(), dy.signum());
+    // offset to fix vertical states
+    let (mut vx, mut vy) = (0, 0);
+    if sx * dx + sy * dy > 2 {
+        (vx, vy) = (sx * (2 - sx * dx), sy * (2 - sy * dy));
+    }
+    (tx + dx - sx + vx, ty + dy - sy + vy)
+}
+
+fn read_step(direction: &str) -> Step {
+    match direction {
+        "U" => Step::Up(1),
+        "D" => Step::Down(1),
+        "R" => Step::Right(1),
+        "L" => Step::Left(1),
+        _ => panic!("invalid direction given")
+    }
+}
+
+pub fn visualize<const XI: i32, const XN: i32, const YI: i32, const YN: i32>(head: Pos, tails: &[Pos]) {
+    let in_tails = |cur| {
+        for (i, t) in tails.iter().enumerate() {
+            if *t == cur {
+                return i + 1;
+            }
+        }
+        0
+    };
+    for row in (XI..XN).rev() {
+        for col in YI..YN {
+            let cur = (row, col);
+            if cur == head {
+                print!("H"); 
+            } else if in_tails(cur) > 0 {
+                print!("{}", in_tails(cur)); 
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+    println!()
+}
+
+/// Pulls a rope of `1 + KNOTS` knots through the motions and counts the 
+/// unique positions visited by its last knot.
+fn simulate<const KNOTS: usize>(motions: &[(String, i32)]) -> usize {
+    // initial values
+    let mut head = (0, 0);
+    let mut tails = [(0, 0); KNOTS];
+
+    // tracker keeps track of the positions the tail visited already
+    let mut tracker: HashSet<Pos> = HashSet::new();
+    // how many unique positions the tail visited
+    let mut cnt = 0;
+
+    for (dir, steps) in motions {
+        for _ in 0..*steps {
+            let s = read_step(dir);
+            head = update_headpos(head, s);
+            let mut prec = head;
+            for tail in tails.iter_mut() {
+                *tail = update_tailpos(*tail, prec);
+                prec = *tail;
+            }
+
+            if tracker.insert(tails[tails.len() - 1]) {
+                cnt += 1;
+            }
+        }
+        // visualize::<-20,20,-20,20>(head, &tails);
+    }
+    cnt
+}
+
+pub struct Day9;
+
+impl Puzzle for Day9 {
+    const DAY: u8 = 9;
+    /// (direction, number of steps) per motion
+    type Input = Vec<(String, i32)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut motions = Vec::new();
+        for (i, lstr) in input.lines().enumerate() {
+            let err = |part: &str, msg: String| Error::parse(Self::DAY, i + 1, column_of(lstr, part), msg);
+            let mut parts = lstr.split(' ');
+            let dir = parts.next().unwrap_or(lstr);
+            if !["U", "D", "R", "L"].contains(&dir) {
+                return Err(err(dir, format!("invalid direction '{}', expected one of U, D, R, L", dir)));
+            }
+            let stepstr = parts.next().ok_or_else(|| err(&lstr[lstr.len()..], String::from("no steps given")))?;
+            let steps = stepstr.parse::<i32>().map_err(|e| err(stepstr, format!("invalid number of steps '{}': {}", stepstr, e)))?;
+            motions.push((String::from(dir), steps));
+        }
+        Ok(motions)
+    }
+
+    fn part1(motions: &Self::Input) -> usize {
+        simulate::<1>(motions)
+    }
+
+    fn part2(motions: &Self::Input) -> usize {
+        simulate::<9>(motions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), 1);
+    }
+
+    #[test]
+    fn larger_example_part2() {
+        let input = Day9::parse(include_str!("example2.txt")).unwrap();
+        assert_eq!(Day9::part2(&input), 36);
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// Error type shared by all puzzle parsers and the runner.
+#[derive(Debug)]
+pub enum Error {
+    /// reading the puzzle input failed
+    Io(std::io::Error),
+    /// malformed puzzle input, `line` and `column` are 1-based
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        msg: String,
+    },
+    /// malformed file other than the puzzle input, e.g. a game definition
+    /// of day 2, `name` is usually its path
+    File {
+        name: String,
+        line: usize,
+        column: usize,
+        msg: String,
+    },
+    /// there is no solver for the day
+    NoSolver(u8),
+    /// invalid command line arguments
+    Usage(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(day: u8, line: usize, column: usize, msg: impl Into<String>) -> Self {
+        Error::Parse { day, line, column, msg: msg.into() }
+    }
+
+    pub fn file(name: impl Into<String>, line: usize, column: usize, msg: impl Into<String>) -> Self {
+        Error::File { name: name.into(), line, column, msg: msg.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse { day, line, column, msg } => {
+                write!(f, "day {} input line {}, column {}: {}", day, line, column, msg)
+            }
+            Error::File { name, line, column, msg } => write!(f, "{} line {}, column {}: {}", name, line, column, msg),
+            Error::NoSolver(day) => write!(f, "no solver for day {}", day),
+            Error::Usage(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse { .. } | Error::File { .. } | Error::NoSolver(_) | Error::Usage(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// 1-based column at which `part` starts, `part` has to be a subslice of `line`.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let off = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize).min(line.len());
+    line[..off].chars().count() + 1
+}
@@ -0,0 +1,42 @@
+use crate::puzzle::Puzzle;
+use crate::rng::Rng;
+
+/// Random puzzle inputs for stress and fuzz testing the solvers.
+///
+/// Generated inputs follow the format and the guarantees of the official
+/// inputs (e.g. a reachable target on day 12 or exactly one uncovered
+/// position on day 15), so both parts have an answer. `size` scales the
+/// input, what it counts is documented per day.
+pub trait Generate: Puzzle {
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generated input of puzzle `P` for `seed`.
+pub fn generate<P: Generate>(seed: u64, size: usize) -> String {
+    P::generate(&mut Rng::new(seed), size)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::{self, Part, DAYS};
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in DAYS {
+            // day 15 part 2 scans all 4000001 rows, too slow for a debug build
+            let parts: &[Part] = if day == 15 { &[Part::One] } else { &Part::BOTH };
+            for seed in 0..4 {
+                let input = runner::generate_day(day, seed, 10).unwrap();
+                let answers = runner::run_day(day, &input, parts);
+                assert!(answers.is_ok(), "day {} seed {}: {}", day, seed, answers.unwrap_err());
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in DAYS {
+            assert_eq!(runner::generate_day(day, 7, 10).unwrap(), runner::generate_day(day, 7, 10).unwrap());
+        }
+    }
+}
@@ -0,0 +1,30 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod generate;
+pub mod puzzle;
+pub mod report;
+pub mod rng;
+pub mod runner;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+pub use error::{Error, Result};
+pub use puzzle::Puzzle;
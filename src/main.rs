@@ -0,0 +1,554 @@
+use std::env;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::answers::{Answers, Verdict};
+use aoc::bench;
+use aoc::{day1, day2, day3, day4, day5};
+use aoc::Puzzle;
+use aoc::report;
+use aoc::runner::{self, Part};
+
+const USAGE: &str = "usage: aoc [--day N] [--part 1|2] [--input FILE] [--answers FILE] [--format text|json]
+           [--game FILE] [--alphabet ITEMS] [--policy abort|skip|clamp]
+       aoc --audit --day 3 [--input FILE] [--alphabet ITEMS]
+       aoc --coverage K --day 4 [--input FILE]
+       aoc --day 5 --crane MODEL [--crane MODEL...] [--drawing] [--after N]
+           [--policy abort|skip|clamp] [--input FILE]
+       aoc --what-if --day 2 [--input FILE] [--game FILE]
+       aoc --rounds --day 2 [--input FILE] [--game FILE] [--report FILE]
+       aoc --bench N [--day N] [--part 1|2] [--input FILE] [--report FILE] [--format text|json]
+       aoc --generate SIZE --day N [--seed S]
+       aoc --stats [--day 1] [--input FILE]
+
+Runs the solvers of all days unless --day is given. Both parts are solved
+unless --part is given. --input overrides the input file of a single day,
+by default src/dayN/input.txt is read. With --input - the input is read
+from stdin (cat input | aoc --day 3 --input -). With --answers every answer is
+compared against the recorded answers in the given TOML file and the exit
+code is non-zero if any of them differs.
+
+--format json prints a JSON array with one object per day and part instead
+of the plain text output: the answer, the time it took to solve the part
+and to parse the input, and the verdict against --answers if given.
+
+--bench times parse, part 1 and part 2 separately over N iterations and
+prints min/median/max per phase. --report additionally writes the timings
+to FILE, as JSON if it ends in .json and as CSV otherwise. With --format
+json the timings are printed as JSON instead of a table.
+
+--generate prints a random puzzle input for --day to stdout, SIZE scales
+it (e.g. the number of elves on day 1 or of files on day 7). The same
+--seed (default 0) always gives the same input, so a generated input can
+be piped into a solver: aoc --generate 1000 --day 7 | aoc --day 7 --input -
+
+--game reads the rules of day 2 from FILE instead of playing rock paper
+scissors, e.g. src/day2/rpsls.txt. Each line is one of
+
+    weapon NAME SCORE OPPONENT_LETTER MY_LETTER
+    beats NAME NAME...
+    outcome lose|draw|win SCORE LETTER
+
+without beats lines every weapon beats the (n - 1) / 2 weapons before it.
+Part 1 reads the second column of the guide as MY_LETTER and part 2 as an
+outcome LETTER, a round is only rejected by the parts it has no meaning
+for.
+
+--alphabet sets the items of day 3 in order of priority, by default
+a-z and A-Z. Any characters may be items, e.g. --alphabet
+abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 adds digits
+with the priorities 53 to 62.
+
+--policy decides what happens to commands of day 5 that move more crates
+than a stack holds or name a stack that is not in the drawing: abort (the
+default) rejects the input, skip leaves the command out and clamp moves
+only the crates the stack holds. Commands that were skipped or clamped are
+reported on stderr with their line and the stack sizes before them.
+
+--audit lists for every rucksack of day 3 all items shared by its two
+compartments, and for every group of three all items shared by its
+rucksacks, with their priorities. Rucksacks and groups without exactly one
+shared item are marked as malformed and counted, the exit code is non-zero
+if there are any.
+
+--coverage merges all section assignments of day 4 and prints the sections
+never cleaned between the first and the last assigned section, the
+sections cleaned by more than K elves and the largest number of elves
+cleaning the same section.
+
+--crane rearranges the crates of day 5 with the given crane model and
+prints the top crates, once per --crane: 9000 or single moves one crate
+at a time, 9001 or stack all crates of a command at once and capacity:K
+at most K crates per step. With --drawing the stacks after the
+rearrangement are drawn in the format of the puzzle input. --after N
+replays only the first N commands and draws the stacks after them,
+--after 0 draws the starting stacks.
+
+--what-if scores the strategy guide of day 2 under every mapping of its
+second column to shapes and to desired outcomes, marks the readings of
+part 1 and part 2 and reports the best and the worst of them, together
+with the scores of the best and the worst possible play.
+
+--rounds prints every round of the strategy guide of day 2 with the
+opponent shape, then for both parts the shape played, the outcome, the
+round score and the running total. --report additionally writes the
+rounds to FILE as CSV.
+
+--stats prints statistics over the calorie totals of all elves of day 1:
+count, mean, median, percentiles, min/max, standard deviation and a
+histogram.";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    bench: Option<usize>,
+    report: Option<PathBuf>,
+    format: Format,
+    generate: Option<usize>,
+    seed: Option<u64>,
+    stats: bool,
+    game: Option<PathBuf>,
+    what_if: bool,
+    rounds: bool,
+    alphabet: Option<String>,
+    audit: bool,
+    coverage: Option<u32>,
+    cranes: Vec<String>,
+    drawing: bool,
+    after: Option<usize>,
+    policy: Option<day5::Policy>,
+}
+
+fn usage_error(msg: &str) -> aoc::Error {
+    aoc::Error::Usage(String::from(msg))
+}
+
+/// Modes like `--stats` print a report instead of the answers, so they
+/// cannot be combined with the options about solving and checking answers.
+fn report_mode(opts: &Options, flag: &str) -> aoc::Result<()> {
+    if opts.bench.is_some() || opts.answers.is_some() || opts.generate.is_some() || opts.part.is_some()
+        || opts.format != Format::Text {
+        return Err(usage_error(&format!("{} cannot be combined with --bench, --answers, --generate, --part or --format", flag)));
+    }
+    Ok(())
+}
+
+fn parse_args(args: &[String]) -> aoc::Result<Options> {
+    let mut opts = Options::default();
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        let mut value = || it.next().ok_or_else(|| usage_error(&format!("missing value for {}", arg)));
+        match arg.as_str() {
+            "--day" => {
+                let v = value()?;
+                let day = v.parse::<u8>().map_err(|_| usage_error(&format!("invalid day '{}'", v)))?;
+                if !runner::DAYS.contains(&day) {
+                    return Err(usage_error(&format!("no solver for day {}", day)));
+                }
+                opts.day = Some(day);
+            }
+            "--part" => {
+                opts.part = match value()?.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    v => return Err(usage_error(&format!("invalid part '{}'", v))),
+                };
+            }
+            "--input" => {
+                opts.input = Some(PathBuf::from(value()?));
+            }
+            "--answers" => {
+                opts.answers = Some(PathBuf::from(value()?));
+            }
+            "--bench" => {
+                let v = value()?;
+                let n = v.parse::<usize>().ok().filter(|n| *n > 0)
+                    .ok_or_else(|| usage_error(&format!("invalid number of iterations '{}'", v)))?;
+                opts.bench = Some(n);
+            }
+            "--report" => {
+                opts.report = Some(PathBuf::from(value()?));
+            }
+            "--generate" => {
+                let v = value()?;
+                let n = v.parse::<usize>().map_err(|_| usage_error(&format!("invalid size '{}'", v)))?;
+                opts.generate = Some(n);
+            }
+            "--seed" => {
+                let v = value()?;
+                let seed = v.parse::<u64>().map_err(|_| usage_error(&format!("invalid seed '{}'", v)))?;
+                opts.seed = Some(seed);
+            }
+            "--game" => {
+                opts.game = Some(PathBuf::from(value()?));
+            }
+            "--alphabet" => {
+                opts.alphabet = Some(value()?.clone());
+            }
+            "--crane" => {
+                let v = value()?;
+                if day5::crane(v).is_none() {
+                    return Err(usage_error(&format!("invalid crane model '{}'", v)));
+                }
+                opts.cranes.push(v.clone());
+            }
+            "--policy" => {
+                let v = value()?;
+                opts.policy = Some(day5::Policy::from_name(v).ok_or_else(|| usage_error(&format!("invalid policy '{}'", v)))?);
+            }
+            "--after" => {
+                let v = value()?;
+                let n = v.parse::<usize>().map_err(|_| usage_error(&format!("invalid number of commands '{}'", v)))?;
+                opts.after = Some(n);
+            }
+            "--drawing" => {
+                opts.drawing = true;
+            }
+            "--coverage" => {
+                let v = value()?;
+                let k = v.parse::<u32>().map_err(|_| usage_error(&format!("invalid number of elves '{}'", v)))?;
+                opts.coverage = Some(k);
+            }
+            "--audit" => {
+                opts.audit = true;
+            }
+            "--rounds" => {
+                opts.rounds = true;
+            }
+            "--what-if" => {
+                opts.what_if = true;
+            }
+            "--stats" => {
+                opts.stats = true;
+            }
+            "--format" => {
+                opts.format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    v => return Err(usage_error(&format!("invalid format '{}'", v))),
+                };
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => return Err(usage_error(&format!("unexpected argument '{}'", arg))),
+        }
+    }
+    if opts.stats {
+        if opts.day.is_some_and(|day| day != 1) {
+            return Err(usage_error("--stats is only available for day 1"));
+        }
+        report_mode(&opts, "--stats")?;
+        opts.day = Some(1);
+    }
+    if opts.input.is_some() && opts.day.is_none() {
+        return Err(usage_error("--input requires --day"));
+    }
+    if opts.bench.is_some() && opts.answers.is_some() {
+        return Err(usage_error("--answers cannot be combined with --bench"));
+    }
+    if opts.report.is_some() && opts.bench.is_none() && !opts.rounds {
+        return Err(usage_error("--report requires --bench or --rounds"));
+    }
+    if opts.generate.is_some() {
+        if opts.day.is_none() {
+            return Err(usage_error("--generate requires --day"));
+        }
+        if opts.bench.is_some() || opts.answers.is_some() || opts.input.is_some() || opts.part.is_some() {
+            return Err(usage_error("--generate cannot be combined with --bench, --answers, --input or --part"));
+        }
+    }
+    if opts.game.is_some() {
+        if opts.day != Some(2) {
+            return Err(usage_error("--game requires --day 2"));
+        }
+        if opts.bench.is_some() || opts.generate.is_some() {
+            return Err(usage_error("--game cannot be combined with --bench or --generate"));
+        }
+    }
+    if opts.alphabet.is_some() {
+        if opts.day != Some(3) {
+            return Err(usage_error("--alphabet requires --day 3"));
+        }
+        if opts.bench.is_some() || opts.generate.is_some() {
+            return Err(usage_error("--alphabet cannot be combined with --bench or --generate"));
+        }
+    }
+    if !opts.cranes.is_empty() {
+        if opts.day != Some(5) {
+            return Err(usage_error("--crane requires --day 5"));
+        }
+        report_mode(&opts, "--crane")?;
+    }
+    if opts.policy.is_some() {
+        if opts.day != Some(5) {
+            return Err(usage_error("--policy requires --day 5"));
+        }
+        if opts.bench.is_some() || opts.generate.is_some() {
+            return Err(usage_error("--policy cannot be combined with --bench or --generate"));
+        }
+    }
+    if (opts.drawing || opts.after.is_some()) && opts.cranes.is_empty() {
+        return Err(usage_error("--drawing and --after require --crane"));
+    }
+    if opts.coverage.is_some() {
+        if opts.day != Some(4) {
+            return Err(usage_error("--coverage requires --day 4"));
+        }
+        report_mode(&opts, "--coverage")?;
+    }
+    if opts.audit {
+        if opts.day != Some(3) {
+            return Err(usage_error("--audit requires --day 3"));
+        }
+        report_mode(&opts, "--audit")?;
+    }
+    if opts.rounds {
+        if opts.day != Some(2) {
+            return Err(usage_error("--rounds requires --day 2"));
+        }
+        report_mode(&opts, "--rounds")?;
+        if opts.what_if {
+            return Err(usage_error("--rounds cannot be combined with --what-if"));
+        }
+    }
+    if opts.what_if {
+        if opts.day != Some(2) {
+            return Err(usage_error("--what-if requires --day 2"));
+        }
+        report_mode(&opts, "--what-if")?;
+    }
+    if opts.seed.is_some() && opts.generate.is_none() {
+        return Err(usage_error("--seed requires --generate"));
+    }
+    Ok(opts)
+}
+
+fn print_answer(part: Part, answer: &str, verdict: Option<&Verdict>) {
+    let tag = match verdict {
+        None => String::new(),
+        Some(Verdict::Pass) => String::from(" [pass]"),
+        Some(Verdict::Fail(expected)) if expected.contains('\n') => format!(" [FAIL, expected]\n{}\n[got]", expected),
+        Some(Verdict::Fail(expected)) => format!(" [FAIL, expected {}]", expected),
+        Some(Verdict::Unknown) => String::from(" [unknown]"),
+    };
+    // multi-line answers (e.g. the CRT image of day 10) start on their own line
+    if answer.contains('\n') {
+        println!("solution {}:{}\n{}", part.number(), tag, answer);
+    } else {
+        println!("solution {}: {}{}", part.number(), answer, tag);
+    }
+}
+
+fn selected_days(opts: &Options) -> Vec<u8> {
+    match opts.day {
+        Some(day) => vec![day],
+        None => runner::DAYS.collect(),
+    }
+}
+
+fn selected_parts(opts: &Options) -> Vec<Part> {
+    match opts.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+/// Reports the commands of day 5 that were skipped or clamped.
+fn warn_illegal(illegal: &[day5::Diagnostic]) {
+    for d in illegal {
+        eprintln!("warning: day 5 input line {}: {}", d.command.line, d);
+    }
+}
+
+/// Reads the puzzle input of `day`, see USAGE for where it comes from.
+fn read_input(opts: &Options, day: u8) -> std::io::Result<String> {
+    match &opts.input {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            std::io::stdin().lock().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path),
+        None => std::fs::read_to_string(runner::default_input(day)),
+    }
+}
+
+fn run_bench(opts: &Options, iterations: usize) -> aoc::Result<()> {
+    let parts = selected_parts(opts);
+    let text = opts.format == Format::Text;
+    let mut timings = Vec::new();
+    if text {
+        println!("{:>3}  {:<6} {:>14} {:>14} {:>14}", "day", "phase", "min", "median", "max");
+    }
+    for day in selected_days(opts) {
+        let t = runner::bench_day(day, &read_input(opts, day)?, &parts, iterations)?;
+        if text {
+            for (phase, s) in t.summaries() {
+                println!("{:>3}  {:<6} {:>14} {:>14} {:>14}", day, phase.name(),
+                         format!("{:.2?}", s.min), format!("{:.2?}", s.median), format!("{:.2?}", s.max));
+            }
+        }
+        timings.push(t);
+    }
+    if !text {
+        print!("{}", bench::to_json(&timings));
+    }
+    if let Some(path) = &opts.report {
+        let report = if path.extension().is_some_and(|ext| ext == "json") {
+            bench::to_json(&timings)
+        } else {
+            bench::to_csv(&timings)
+        };
+        std::fs::write(path, report)?;
+    }
+    Ok(())
+}
+
+/// Returns whether all answers matched or were unknown.
+fn run(opts: &Options) -> aoc::Result<bool> {
+    if let (Some(size), Some(day)) = (opts.generate, opts.day) {
+        print!("{}", runner::generate_day(day, opts.seed.unwrap_or(0), size)?);
+        return Ok(true);
+    }
+    if opts.stats {
+        let input = read_input(opts, 1)?;
+        match day1::Stats::from_lines(input.lines())? {
+            Some(stats) => print!("{}", stats),
+            None => println!("no elves"),
+        }
+        return Ok(true);
+    }
+    if let Some(iterations) = opts.bench {
+        run_bench(opts, iterations)?;
+        return Ok(true);
+    }
+    let answers = match &opts.answers {
+        Some(path) => Some(Answers::open(path)?),
+        None => None,
+    };
+    let game = match &opts.game {
+        Some(path) => Some(day2::Game::open(path)?),
+        None => None,
+    };
+    if opts.rounds {
+        let guide = day2::Guide::parse(game.unwrap_or_default(), &read_input(opts, 2)?)?;
+        print!("{}", day2::round_log(&guide, false));
+        if let Some(path) = &opts.report {
+            std::fs::write(path, day2::round_log(&guide, true))?;
+        }
+        return Ok(true);
+    }
+    if opts.what_if {
+        let guide = day2::Guide::parse(game.unwrap_or_default(), &read_input(opts, 2)?)?;
+        print!("{}", day2::analyze(&guide));
+        return Ok(true);
+    }
+    let alphabet = match &opts.alphabet {
+        Some(items) => Some(day3::Alphabet::new(items.chars()).map_err(|c| {
+            usage_error(&format!("item '{}' is listed twice in --alphabet", c))
+        })?),
+        None => None,
+    };
+    if !opts.cranes.is_empty() {
+        let policy = opts.policy.unwrap_or_default();
+        let ((stacks, commands), illegal) = day5::parse_with_policy(&read_input(opts, 5)?, policy)?;
+        warn_illegal(&illegal);
+        for name in &opts.cranes {
+            let crane = day5::crane(name).expect("validated by parse_args");
+            let mut replay = day5::Replay::new(crane.as_ref(), &stacks, &commands);
+            replay.seek(opts.after.unwrap_or(commands.len()));
+            if replay.position() < replay.len() {
+                println!("{} after {} of {} commands: {}", crane.name(), replay.position(), replay.len(),
+                         day5::tops(replay.stacks()));
+            } else {
+                println!("{}: {}", crane.name(), day5::tops(replay.stacks()));
+            }
+            if opts.drawing || opts.after.is_some() {
+                print!("{}", replay.render());
+            }
+        }
+        return Ok(true);
+    }
+    if let Some(k) = opts.coverage {
+        let pairs = <day4::Day4 as Puzzle>::parse(&read_input(opts, 4)?)?;
+        print!("{}", day4::Report { coverage: &day4::coverage(&pairs), k });
+        return Ok(true);
+    }
+    if opts.audit {
+        let rucksacks = day3::Rucksacks::parse(alphabet.unwrap_or_default(), &read_input(opts, 3)?)?;
+        let audit = rucksacks.audit(2, 3);
+        print!("{}", audit);
+        return Ok(audit.malformed_rucksacks() == 0 && audit.malformed_groups() == 0);
+    }
+    let parts = selected_parts(opts);
+
+    let text = opts.format == Format::Text;
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    let mut days = Vec::new();
+    for day in selected_days(opts) {
+        if text {
+            println!("day {}", day);
+        }
+        let input = read_input(opts, day)?;
+        let computed = match (&game, &alphabet, opts.policy) {
+            (Some(game), _, _) => day2::solve_with_game(game, &input, &parts)?,
+            (_, Some(alphabet), _) => day3::solve_with_alphabet(alphabet, &input, &parts)?,
+            (_, _, Some(policy)) => {
+                let (computed, illegal) = day5::solve_with_policy(policy, &input, &parts)?;
+                warn_illegal(&illegal);
+                computed
+            }
+            _ => runner::run_day(day, &input, &parts)?,
+        };
+        for part in &parts {
+            if let Some(pa) = computed.get(*part) {
+                let verdict = answers.as_ref().map(|a| a.verify(day, *part, &pa.answer));
+                match verdict {
+                    Some(Verdict::Pass) => passed += 1,
+                    Some(Verdict::Fail(_)) => failed += 1,
+                    Some(Verdict::Unknown) => unknown += 1,
+                    None => {}
+                }
+                if text {
+                    print_answer(*part, &pa.answer, verdict.as_ref());
+                }
+            }
+        }
+        days.push(computed);
+    }
+    if !text {
+        print!("{}", report::to_json(&days, answers.as_ref()));
+    } else if answers.is_some() {
+        println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    }
+    Ok(failed == 0)
+}
+
+fn main() -> ExitCode {
+    let argv: Vec<String> = env::args().collect();
+    match parse_args(&argv[1..]).and_then(|opts| run(&opts)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            if let aoc::Error::Usage(_) = e {
+                eprintln!("\n{}", USAGE);
+            }
+            ExitCode::from(2)
+        }
+    }
+}
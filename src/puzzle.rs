@@ -0,0 +1,29 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use crate::error::Result;
+
+/// Common interface of all days: parse the puzzle input once, then solve
+/// both parts on the parsed representation.
+pub trait Puzzle {
+    /// Day of the advent calendar this puzzle belongs to.
+    const DAY: u8;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Reads the whole puzzle input from `reader` and parses it.
+    fn parse_reader(mut reader: impl Read) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
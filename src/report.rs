@@ -0,0 +1,91 @@
+use std::fmt::Write as _;
+
+use crate::answers::{Answers, Verdict};
+use crate::runner::{DayAnswers, Part};
+
+/// JSON string literal of `s`.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// `"diagnostics"` member of a part, empty if there is nothing to report.
+fn diagnostics(verdict: Option<&Verdict>) -> String {
+    match verdict {
+        None => String::new(),
+        Some(Verdict::Pass) => String::from(", \"diagnostics\": {\"verdict\": \"pass\"}"),
+        Some(Verdict::Fail(expected)) => {
+            format!(", \"diagnostics\": {{\"verdict\": \"fail\", \"expected\": {}}}", quote(expected))
+        }
+        Some(Verdict::Unknown) => String::from(", \"diagnostics\": {\"verdict\": \"unknown\"}"),
+    }
+}
+
+/// One object per solved day and part with the answer, the time it took to
+/// solve the part and to parse the input, and the verdict against `answers`
+/// if given.
+pub fn to_json(days: &[DayAnswers], answers: Option<&Answers>) -> String {
+    let mut rows = Vec::new();
+    for d in days {
+        for part in Part::BOTH {
+            if let Some(pa) = d.get(part) {
+                let verdict = answers.map(|a| a.verify(d.day, part, &pa.answer));
+                rows.push(format!("  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"parse_ns\": {}{}}}",
+                                  d.day, part.number(), quote(&pa.answer), pa.elapsed.as_nanos(), d.parse.as_nanos(),
+                                  diagnostics(verdict.as_ref())));
+            }
+        }
+    }
+    if rows.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::runner::PartAnswer;
+
+    #[test]
+    fn quoted_strings() {
+        assert_eq!(quote("CMZ"), "\"CMZ\"");
+        assert_eq!(quote("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(quote("##.\n.##\t\r\u{1}é"), "\"##.\\n.##\\t\\r\\u0001é\"");
+    }
+
+    #[test]
+    fn answers_with_verdicts() {
+        let answer = |s: &str, ns| Some(PartAnswer { answer: String::from(s), elapsed: Duration::from_nanos(ns) });
+        let days = [
+            DayAnswers { day: 1, parse: Duration::from_nanos(10), part1: answer("24000", 20), part2: answer("45000", 30) },
+            DayAnswers { day: 10, parse: Duration::from_nanos(5), part1: None, part2: answer("#.\n.#", 6) },
+        ];
+        assert_eq!(to_json(&days, None), "[\n  \
+            {\"day\": 1, \"part\": 1, \"answer\": \"24000\", \"elapsed_ns\": 20, \"parse_ns\": 10},\n  \
+            {\"day\": 1, \"part\": 2, \"answer\": \"45000\", \"elapsed_ns\": 30, \"parse_ns\": 10},\n  \
+            {\"day\": 10, \"part\": 2, \"answer\": \"#.\\n.#\", \"elapsed_ns\": 6, \"parse_ns\": 5}\n]\n");
+
+        let answers = Answers::parse("[day1]\npart1 = 24000\npart2 = 45001\n").unwrap();
+        let json = to_json(&days, Some(&answers));
+        assert!(json.contains("\"answer\": \"24000\", \"elapsed_ns\": 20, \"parse_ns\": 10, \"diagnostics\": {\"verdict\": \"pass\"}}"));
+        assert!(json.contains("\"diagnostics\": {\"verdict\": \"fail\", \"expected\": \"45001\"}}"));
+        assert!(json.contains("\"parse_ns\": 5, \"diagnostics\": {\"verdict\": \"unknown\"}}"));
+        assert_eq!(to_json(&[], None), "[]\n");
+    }
+}
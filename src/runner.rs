@@ -0,0 +1,132 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::bench::{measure, DayTimings};
+use crate::error::{Error, Result};
+use crate::generate::generate;
+use crate::puzzle::Puzzle;
+use crate::{day1, day2, day3, day4, day5, day6, day7, day8, day9};
+use crate::{day10, day11, day12, day13, day14, day15, day16, day17, day18};
+
+/// Days with a solver, in calendar order.
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=18;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Rendered answer of a single part and the time it took to compute it.
+#[derive(Debug, Clone)]
+pub struct PartAnswer {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Answers of a single day, `None` for parts that were not requested.
+#[derive(Debug)]
+pub struct DayAnswers {
+    pub day: u8,
+    /// time it took to parse the input
+    pub parse: Duration,
+    pub part1: Option<PartAnswer>,
+    pub part2: Option<PartAnswer>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&PartAnswer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+fn timed(f: impl FnOnce() -> String) -> PartAnswer {
+    let start = Instant::now();
+    let answer = f();
+    PartAnswer { answer, elapsed: start.elapsed() }
+}
+
+/// Parses the input once and solves the requested parts of puzzle `P`.
+pub fn solve<P: Puzzle>(input: &str, parts: &[Part]) -> Result<DayAnswers> {
+    solve_with(P::DAY, parts, || P::parse(input), P::part1, P::part2)
+}
+
+/// Like [`solve`] for solvers that need more than the puzzle input, e.g. a
+/// custom game on day 2.
+pub fn solve_with<I, A1: Display, A2: Display>(day: u8, parts: &[Part], parse: impl FnOnce() -> Result<I>,
+                                               part1: impl FnOnce(&I) -> A1, part2: impl FnOnce(&I) -> A2)
+                                               -> Result<DayAnswers> {
+    let start = Instant::now();
+    let input = parse()?;
+    let mut answers = DayAnswers { day, parse: start.elapsed(), part1: None, part2: None };
+    if parts.contains(&Part::One) {
+        answers.part1 = Some(timed(|| part1(&input).to_string()));
+    }
+    if parts.contains(&Part::Two) {
+        answers.part2 = Some(timed(|| part2(&input).to_string()));
+    }
+    Ok(answers)
+}
+
+/// Location of the puzzle input checked in next to the solver of `day`.
+pub fn default_input(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("day{}", day)).join("input.txt")
+}
+
+/// Calls the generic function `$f` with the puzzle type of `$day`, evaluates
+/// to `None` if there is no solver for that day.
+macro_rules! dispatch {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => Some($f::<day1::Day1>($($arg),*)),
+            2 => Some($f::<day2::Day2>($($arg),*)),
+            3 => Some($f::<day3::Day3>($($arg),*)),
+            4 => Some($f::<day4::Day4>($($arg),*)),
+            5 => Some($f::<day5::Day5>($($arg),*)),
+            6 => Some($f::<day6::Day6>($($arg),*)),
+            7 => Some($f::<day7::Day7>($($arg),*)),
+            8 => Some($f::<day8::Day8>($($arg),*)),
+            9 => Some($f::<day9::Day9>($($arg),*)),
+            10 => Some($f::<day10::Day10>($($arg),*)),
+            11 => Some($f::<day11::Day11>($($arg),*)),
+            12 => Some($f::<day12::Day12>($($arg),*)),
+            13 => Some($f::<day13::Day13>($($arg),*)),
+            14 => Some($f::<day14::Day14>($($arg),*)),
+            15 => Some($f::<day15::Day15>($($arg),*)),
+            16 => Some($f::<day16::Day16>($($arg),*)),
+            17 => Some($f::<day17::Day17>($($arg),*)),
+            18 => Some($f::<day18::Day18>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+
+/// Solves the requested parts of `day` for the puzzle input `input`.
+pub fn run_day(day: u8, input: &str, parts: &[Part]) -> Result<DayAnswers> {
+    dispatch!(day, solve(input, parts)).unwrap_or_else(|| Err(Error::NoSolver(day)))
+}
+
+/// Times parse and the requested parts of `day` over `iterations` runs.
+pub fn bench_day(day: u8, input: &str, parts: &[Part], iterations: usize) -> Result<DayTimings> {
+    dispatch!(day, measure(input, parts, iterations)).unwrap_or_else(|| Err(Error::NoSolver(day)))
+}
+
+/// Random input of `day` for `seed`, see [`crate::generate::Generate`].
+pub fn generate_day(day: u8, seed: u64, size: usize) -> Result<String> {
+    dispatch!(day, generate(seed, size)).ok_or(Error::NoSolver(day))
+}